@@ -0,0 +1,35 @@
+//! Benchmarks parsing and both parts of every solver on the real puzzle inputs.
+//!
+//! `cargo bench` runs them all, `cargo bench -- day16` only day16's (see `bench::selected`).
+//! Median times are saved to 'target/bench/baseline.csv', and each run is compared with the
+//! previous one.
+
+use std::path::Path;
+
+use rust_advent_2022::bench::{self, Options, BASELINE_FILE};
+use rust_advent_2022::{read_input, registry};
+
+fn main() {
+    // Cargo passes '--bench', anything else is a filter.
+    let filters = std::env::args().skip(1).filter(|arg| !arg.starts_with("--")).collect::<Vec<String>>();
+    let baseline_path = Path::new(BASELINE_FILE);
+    let baseline = bench::load_baseline(baseline_path).unwrap_or_else(|error| panic!("{}", error));
+
+    let registry = registry();
+    let mut measurements = Vec::new();
+    for solver in registry.iter_all() {
+        let selected = |id:&str| bench::selected(id, &filters);
+        if !["parse", "part1", "part2"].iter().any(|step| selected(&bench::id(solver, step))) {
+            continue;
+        }
+        let lines = read_input(solver.info().year, solver.info().day, false).unwrap_or_else(|error| panic!("{}", error));
+        let results = bench::bench_solver(solver, &lines, false, &Options::default()).unwrap_or_else(|error| panic!("{}", error));
+        for measurement in results.into_iter().filter(|m| selected(&m.id)) {
+            eprintln!("{}: {:.3}ms", measurement.id, measurement.median().as_secs_f64() * 1000.0);
+            measurements.push(measurement);
+        }
+    }
+
+    print!("{}", bench::table(&measurements, &baseline));
+    bench::save_baseline(baseline_path, &measurements).unwrap_or_else(|error| panic!("{}", error));
+}
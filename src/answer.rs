@@ -0,0 +1,140 @@
+use std::fmt;
+
+use crate::grid::Grid;
+use crate::solver::AnswerType;
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Wide enough for any signed or unsigned 64-bit answer.
+    Integer(i128),
+    Text(String),
+    /// Lit pixels, e.g. the day 10 CRT.  They usually spell out some letters (see 'ocr').
+    Picture(Grid<bool>),
+    /// No answer (e.g. the missing second part of day 25).
+    None,
+}
+
+impl Answer {
+    pub fn answer_type(&self) -> AnswerType {
+        match self {
+            Answer::Integer(_) => AnswerType::Integer,
+            Answer::Text(_) => AnswerType::Text,
+            Answer::Picture(_) => AnswerType::Picture,
+            Answer::None => AnswerType::Empty,
+        }
+    }
+
+    /// The letters in a picture, None if it isn't a picture or can't be read.
+    pub fn ocr(&self) -> Option<String> {
+        match self {
+            Answer::Picture(picture) => ocr(picture),
+            _ => None,
+        }
+    }
+
+    /// True if the answer matches the expected answer as written in the answers manifest (or
+    /// on the puzzle page).  Pictures match either their letters or the picture itself.
+    pub fn matches(&self, expected:&str) -> bool {
+        match self {
+            Answer::Integer(n) => expected.trim().parse::<i128>() == Ok(*n),
+            Answer::Text(text) => text == expected,
+            Answer::Picture(_) => self.to_string().trim() == expected.trim() || self.ocr().is_some_and(|letters| letters == expected.trim()),
+            Answer::None => expected.is_empty(),
+        }
+    }
+
+    /// The answer as a JSON value, a number for integers and null for no answer.
+    pub fn to_json(&self) -> String {
+        match self {
+            Answer::Integer(n) => n.to_string(),
+            Answer::None => "null".to_string(),
+            _ => crate::report::json_string(&self.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    /// Pictures are drawn with '#' and '.', starting on a new line.
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Picture(picture) => write!(f, "\n{}", picture.render(|lit| if *lit {'#'} else {'.'}).trim_end()),
+            Answer::None => Ok(()),
+        }
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, expected:&str) -> bool {
+        self.matches(expected)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, expected:&&str) -> bool {
+        self.matches(expected)
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(n:$t) -> Self {
+                // Every type here is at most 64 bits, so fits.
+                Answer::Integer(n as i128)
+            }
+        }
+    )*};
+}
+
+from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text:String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+    fn from(picture:Grid<bool>) -> Self {
+        Answer::Picture(picture)
+    }
+}
+
+// The letters used in the pictures, each 4 pixels wide and 6 high with a column between them.
+const FONT:[(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Read the letters in a picture, None unless every letter is recognised.
+pub fn ocr(picture:&Grid<bool>) -> Option<String> {
+    let (width, height) = (4, 6);
+    if picture.height() != height {
+        return None;
+    }
+    (0..picture.width().div_ceil(width + 1)).map(|letter| {
+        let glyph = (0..height).map(|y| (0..width).map(|x| {
+            if picture.get((letter * (width + 1) + x) as isize, y as isize).copied().unwrap_or(false) {'#'} else {'.'}
+        }).collect::<String>()).collect::<Vec<String>>();
+        FONT.iter().find(|(_, rows)| rows.iter().eq(glyph.iter())).map(|(c, _)| *c)
+    }).collect()
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+
+/// Default location of the answers manifest, relative to the current directory.
+pub const ANSWERS_FILE:&str = "answers.toml";
+
+/// Known answers for puzzle inputs, keyed by year, day and input file name, e.g.
+///
+/// ```toml
+/// [2022.1]
+/// "day1_input.txt" = { part1 = "71934", part2 = "211447" }
+/// ```
+#[derive(Default, Debug)]
+pub struct Answers {
+    answers: HashMap<(u16, u8, String), [Option<String>; 2]>,
+}
+
+impl Answers {
+    /// Parse a manifest, 'path' is only used to describe it in errors.
+    pub fn parse(text:&str, path:&str) -> Result<Self> {
+        let error = |message:String| Error::Answers {path: path.to_string(), message};
+        let table = text.parse::<toml::Table>().map_err(|e| error(e.message().to_string()))?;
+
+        let mut answers = HashMap::new();
+        for (year, days) in &table {
+            let year_number = year.parse::<u16>().map_err(|_| error(format!("invalid year '{}'", year)))?;
+            let days = days.as_table().ok_or_else(|| error(format!("expected a table of days for {}", year)))?;
+            for (day, inputs) in days {
+                let day_number = day.parse::<u8>().map_err(|_| error(format!("invalid day '{}.{}'", year, day)))?;
+                let inputs = inputs.as_table().ok_or_else(|| error(format!("expected a table of inputs for {}.{}", year, day)))?;
+                for (input, parts) in inputs {
+                    let name = format!("{}.{}.\"{}\"", year, day, input);
+                    let parts = parts.as_table().ok_or_else(|| error(format!("expected part1/part2 for {}", name)))?;
+                    let mut part_answers = [None, None];
+                    for (part, answer) in parts {
+                        let index = match part.as_str() {
+                            "part1" => 0,
+                            "part2" => 1,
+                            _ => return Err(error(format!("unexpected key '{}' for {}, expected part1 or part2", part, name))),
+                        };
+                        let answer = answer.as_str().ok_or_else(|| error(format!("{}.{} must be a string", name, part)))?;
+                        part_answers[index] = Some(answer.to_string());
+                    }
+                    answers.insert((year_number, day_number, input.clone()), part_answers);
+                }
+            }
+        }
+        Ok(Answers {answers})
+    }
+
+    /// Load the manifest at 'path'.
+    pub fn load(path:impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().display().to_string();
+        let text = std::fs::read_to_string(&path).map_err(|source| Error::Io {path: path.clone(), source})?;
+        Answers::parse(&text, &path)
+    }
+
+    /// Load 'answers.toml', if there is one.  Without it, every answer is unknown.
+    pub fn load_default() -> Result<Self> {
+        if Path::new(ANSWERS_FILE).exists() {
+            Answers::load(ANSWERS_FILE)
+        } else {
+            Ok(Answers::default())
+        }
+    }
+
+    /// True if the manifest has an entry for any of the day's inputs.
+    pub fn contains_day(&self, year:u16, day:u8) -> bool {
+        self.answers.keys().any(|(y, d, _)| (*y, *d) == (year, day))
+    }
+
+    /// The known answer for part 1 or 2 of the day's puzzle, for the named input file.
+    pub fn get(&self, year:u16, day:u8, input:&str, part:u8) -> Option<&str> {
+        let parts = self.answers.get(&(year, day, input.to_string()))?;
+        parts.get(part as usize - 1)?.as_deref()
+    }
+}
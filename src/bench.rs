@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::cancel::Cancel;
+use crate::error::{Error, Result};
+use crate::params::Overrides;
+use crate::solver::Solver;
+
+/// Where the benchmarks save their median times, to compare the next run with.
+pub const BASELINE_FILE:&str = "target/bench/baseline.csv";
+
+/// How long to spend on each benchmark.  Every benchmark is run at least once after warming up,
+/// so the slow days take as long as they take.
+#[derive(Clone, Debug)]
+pub struct Options {
+    pub warm_up: Duration,
+    pub measure: Duration,
+    pub max_samples: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {warm_up: Duration::from_millis(200), measure: Duration::from_secs(1), max_samples: 100}
+    }
+}
+
+/// Timings for one step (parse, part 1 or part 2) of a solver.
+pub struct Measurement {
+    /// Stable name for comparing results, e.g. '2022/day16/part2'.
+    pub id: String,
+    pub samples: Vec<Duration>,
+}
+
+impl Measurement {
+    pub fn min(&self) -> Duration {
+        self.samples.iter().copied().min().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut samples = self.samples.clone();
+        samples.sort();
+        samples.get(samples.len() / 2).copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.iter().copied().max().unwrap_or_default()
+    }
+}
+
+/// The benchmark ID for a step of the solver, e.g. '2022/day2_old/parse'.
+pub fn id(solver:&dyn Solver, step:&str) -> String {
+    format!("{}/{}/{}", solver.info().year, solver.info().name, step)
+}
+
+/// True if the benchmark ID is selected by the filters: all of them if there are none, otherwise
+/// those with a whole part of the ID (or several) equal to a filter, e.g. 'day1' or 'day1/part2'
+/// select '2022/day1/part2' but not '2022/day10/part2'.
+pub fn selected(id:&str, filters:&[String]) -> bool {
+    filters.is_empty() || filters.iter().any(|filter| format!("/{}/", id).contains(&format!("/{}/", filter)))
+}
+
+/// Run 'f' repeatedly, until the time is up or there are enough samples, timing each run on its
+/// own.  It's always run at least once after the warm-up.
+pub(crate) fn measure<T>(id:String, options:&Options, mut f:impl FnMut() -> T) -> Measurement {
+    let start = Instant::now();
+    while start.elapsed() < options.warm_up {
+        black_box(f());
+    }
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.is_empty() || (start.elapsed() < options.measure && samples.len() < options.max_samples) {
+        let sample_start = Instant::now();
+        black_box(f());
+        samples.push(sample_start.elapsed());
+    }
+    Measurement {id, samples}
+}
+
+/// Benchmark parsing the lines and each part of the puzzle, with the default parameters.
+pub fn bench_solver(solver:&dyn Solver, lines:&[String], sample:bool, options:&Options) -> Result<Vec<Measurement>> {
+    let overrides = Overrides::new();
+    let input = solver.parse(lines, sample, &overrides)?;
+    Ok(vec![measure(id(solver, "parse"), options, || solver.parse(lines, sample, &overrides)),
+            measure(id(solver, "part1"), options, || solver.part1(input.as_ref(), &Cancel::new())),
+            measure(id(solver, "part2"), options, || solver.part2(input.as_ref(), &Cancel::new()))])
+}
+
+// Durations vary from nanoseconds to seconds, so pick the units to suit.
+fn format_duration(time:Duration) -> String {
+    let nanos = time.as_nanos();
+    match nanos {
+        0..=999 => format!("{}ns", nanos),
+        1_000..=999_999 => format!("{:.2}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+/// A table of the timings, with the change in the median from the baseline (if there is one).
+pub fn table(measurements:&[Measurement], baseline:&HashMap<String, Duration>) -> String {
+    let width = measurements.iter().map(|m| m.id.len()).max().unwrap_or(0);
+    let mut table = String::new();
+    writeln!(table, "{:<width$}  {:>12}  {:>12}  {:>12}  {:>7}  Change", "Benchmark", "Min", "Median", "Max", "Samples", width = width).unwrap();
+    for m in measurements {
+        let change = match baseline.get(&m.id) {
+            Some(previous) if !previous.is_zero() => {
+                format!("{:+.1}%", (m.median().as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0)
+            },
+            _ => "-".to_string(),
+        };
+        writeln!(table, "{:<width$}  {:>12}  {:>12}  {:>12}  {:>7}  {}", m.id, format_duration(m.min()),
+                 format_duration(m.median()), format_duration(m.max()), m.samples.len(), change, width = width).unwrap();
+    }
+    table
+}
+
+/// Read the median times saved by 'save_baseline', an empty baseline if there isn't one.
+pub fn load_baseline(path:&Path) -> Result<HashMap<String, Duration>> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(source) => return Err(Error::Io {path: path.display().to_string(), source}),
+    };
+    Ok(text.lines()
+           .filter_map(|line| line.split_once(','))
+           .filter_map(|(id, nanos)| Some((id.to_string(), Duration::from_nanos(nanos.parse().ok()?))))
+           .collect())
+}
+
+/// Save the median times as 'id,nanoseconds' lines, keeping any other entries in the baseline.
+pub fn save_baseline(path:&Path, measurements:&[Measurement]) -> std::io::Result<()> {
+    let mut baseline = load_baseline(path).unwrap_or_default();
+    baseline.extend(measurements.iter().map(|m| (m.id.clone(), m.median())));
+    let mut entries = baseline.into_iter().collect::<Vec<(String, Duration)>>();
+    entries.sort();
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+    std::fs::write(path, entries.iter().map(|(id, time)| format!("{},{}\n", id, time.as_nanos())).collect::<String>())
+}
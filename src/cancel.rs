@@ -0,0 +1,50 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+
+/// Lets a long running solver be stopped, either after a time limit or from another thread.
+/// Solvers that might search for a long time check it every so often, and give up with an
+/// `Error::Cancelled` once it's cancelled.
+#[derive(Clone, Debug, Default)]
+pub struct Cancel {
+    deadline: Option<(Instant, Duration)>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Cancel {
+    /// A token that's only cancelled by 'cancel'.
+    pub fn new() -> Self {
+        Cancel::default()
+    }
+
+    /// A token that's cancelled once 'limit' has passed, or no token for no limit.  A limit too
+    /// far in the future to represent is never reached, so is no limit.
+    pub fn with_timeout(limit:Option<Duration>) -> Self {
+        let deadline = limit.and_then(|limit| Some((Instant::now().checked_add(limit)?, limit)));
+        Cancel {deadline, ..Cancel::default()}
+    }
+
+    /// Cancel the token, and every clone of it.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// True once the token is cancelled or its time is up.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.deadline.is_some_and(|(deadline, _)| Instant::now() >= deadline)
+    }
+
+    /// An error if the token is cancelled, for solvers to return once they've stopped.  It only
+    /// reports the time limit if that's why it was cancelled.
+    pub fn check(&self) -> Result<()> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            Err(Error::Cancelled {timeout: None})
+        } else if let Some((_, limit)) = self.deadline.filter(|(deadline, _)| Instant::now() >= *deadline) {
+            Err(Error::Cancelled {timeout: Some(limit)})
+        } else {
+            Ok(())
+        }
+    }
+}
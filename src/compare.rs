@@ -0,0 +1,163 @@
+use std::fmt;
+
+use crate::cancel::Cancel;
+use crate::error::{Error, Result};
+use crate::input::InputSource;
+use crate::params::Overrides;
+use crate::registry::Registry;
+use crate::solver::Solver;
+
+/// Number of generated inputs to compare the implementations on, for days with a generator.
+pub const GENERATED_INPUTS:usize = 100;
+
+/// Makes random puzzle inputs for a day, for comparing its implementations.
+pub type Generator = fn(&mut Rng) -> Vec<String>;
+
+/// A small pseudo-random number generator (xorshift), so that generated inputs can be
+/// reproduced from their seed.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed:u64) -> Self {
+        // Xorshift never leaves zero, so mix the seed first.
+        Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in 0..n ('n' must be positive).
+    pub fn below(&mut self, n:usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// One of the items, which mustn't be empty.
+    pub fn choose<'a, T>(&mut self, items:&'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+/// The implementations of a day giving different answers for a part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: u8,
+    /// Which input they disagreed on, e.g. 'the input' or 'generated input 7'.
+    pub source: String,
+    /// The fewest lines of it they still disagree on.
+    pub lines: Vec<String>,
+    /// Each implementation's answer for those lines (or its error).
+    pub answers: Vec<(&'static str, std::result::Result<String, String>)>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        let answers = self.answers.iter().map(|(name, answer)| match answer {
+            Ok(answer) => format!("{} = {}", name, answer),
+            Err(error) => format!("{} failed ({})", name, error),
+        }).collect::<Vec<String>>();
+        write!(f, "part {} disagrees on {}: {}", self.part, self.source, answers.join(", "))?;
+        match self.lines.as_slice() {
+            [line] => write!(f, "\n  minimal failing line: '{}'", line),
+            lines => {
+                write!(f, "\n  minimal failing input ({} lines):", lines.len())?;
+                lines.iter().try_for_each(|line| write!(f, "\n    {}", line))
+            },
+        }
+    }
+}
+
+/// The result of comparing a day's implementations.
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    /// The implementations' names, the default first.
+    pub solvers: Vec<&'static str>,
+    /// Number of inputs they were run on.
+    pub inputs: usize,
+    /// At most one disagreement for each part.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        if self.mismatches.is_empty() {
+            return writeln!(f, "{} agree on {} input{}", self.solvers.join(", "), self.inputs, if self.inputs == 1 {""} else {"s"});
+        }
+        self.mismatches.iter().try_for_each(|mismatch| writeln!(f, "{}", mismatch))
+    }
+}
+
+/// Run every implementation of the day on its input from 'input' and on 'generated' inputs from
+/// the day's generator (if it has one), reporting the first input each part disagrees on,
+/// shrunk to the fewest lines that still show it.  Answers agree if they're equal, or if every
+/// implementation fails (so a day with only one implementation always agrees).
+#[allow(clippy::too_many_arguments)]
+pub fn compare_day(registry:&Registry, year:u16, day:u8, input:InputSource, sample:bool, overrides:&Overrides,
+                   generated:usize, cancel:&Cancel) -> Result<Comparison> {
+    let solvers = registry.implementations(year, day).collect::<Vec<&dyn Solver>>();
+    if solvers.is_empty() {
+        return Err(Error::Unsupported {year, day, solver: None});
+    }
+    let mut inputs = vec![("the input".to_string(), input.read_lines(year, day, sample)?)];
+    if let Some(generator) = registry.generator(year, day) {
+        inputs.extend((0..generated).map(|seed| (format!("generated input {}", seed), generator(&mut Rng::new(seed as u64)))));
+    }
+
+    let mut mismatches = Vec::new();
+    for part in [1, 2] {
+        for (source, lines) in &inputs {
+            if let Some(mut mismatch) = compare_lines(&solvers, lines, part, sample, overrides, cancel)? {
+                mismatch.source = source.clone();
+                mismatches.push(mismatch);
+                break;
+            }
+        }
+    }
+    Ok(Comparison {solvers: solvers.iter().map(|solver| solver.info().name).collect(), inputs: inputs.len(), mismatches})
+}
+
+/// Compare the implementations' answers for the part on the lines, returning any disagreement
+/// with the lines shrunk as far as possible (its source is left empty).  Fails once 'cancel' is
+/// cancelled, as the answers can't be trusted then.
+pub fn compare_lines(solvers:&[&dyn Solver], lines:&[String], part:u8, sample:bool, overrides:&Overrides,
+                     cancel:&Cancel) -> Result<Option<Mismatch>> {
+    let answers = |lines:&[String]| {
+        solvers.iter().map(|solver| {
+            let answer = solver.solve(lines, part == 2, sample, overrides, cancel);
+            (solver.info().name, answer.map(|answer| answer.to_string()).map_err(|error| error.to_string()))
+        }).collect::<Vec<_>>()
+    };
+    let disagree = |lines:&[String]| {
+        let answers = answers(lines);
+        cancel.check()?;
+        // Failures are compared by whether they failed, as their messages may differ.
+        let outcome = |answer:&std::result::Result<String, String>| answer.as_ref().ok().cloned();
+        Ok(answers.iter().any(|(_, answer)| outcome(answer) != outcome(&answers[0].1)).then_some(answers))
+    };
+    if disagree(lines)?.is_none() {
+        return Ok(None);
+    }
+
+    // Remove chunks of lines, halving the chunks each time none can be removed.
+    let mut lines = lines.to_vec();
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        let mut removed = false;
+        while start < lines.len() {
+            let mut candidate = lines.clone();
+            candidate.drain(start..(start + chunk).min(lines.len()));
+            if !candidate.is_empty() && disagree(&candidate)?.is_some() {
+                lines = candidate;
+                removed = true;
+            } else {
+                start += chunk;
+            }
+        }
+        chunk = if removed {chunk.min(lines.len() / 2)} else {chunk / 2};
+    }
+    Ok(disagree(&lines)?.map(|answers| Mismatch {part, source: String::new(), lines, answers}))
+}
@@ -0,0 +1,212 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::io::BufRead;
+
+use crate::error::{Error, Line, Result};
+use crate::params::Overrides;
+use crate::registry::Registry;
+use crate::solver::{AnswerType, SolverInfo, StreamSolver};
+
+/// Puzzle parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// Number of elves carrying the most calories to total, for part 2.
+    pub top: usize,
+}
+
+impl Params {
+    pub const NAMES:&'static [&'static str] = &["top"];
+
+    /// The defaults for the sample or real input.
+    pub fn new(_sample:bool) -> Self {
+        Params {top: 3}
+    }
+
+    /// The parameters, with any overrides.
+    pub fn with(self, overrides:&Overrides) -> Result<Self> {
+        Ok(Params {top: overrides.get(1, "top", self.top)?})
+    }
+}
+
+/// An elf's total calories, and its position in the input (from 1).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub calories: u64,
+}
+
+/// The elves carrying the most calories, and some statistics about the rest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Summary {
+    /// Number of elves.
+    pub elves: usize,
+    /// Calories carried by all the elves.
+    pub calories: u64,
+    /// The elves carrying the most, most first (and then in input order).
+    pub top: Vec<Elf>,
+    /// Number of other elves carrying as much as the last of the top elves.
+    pub ties: usize,
+    // Every elf's total, when they're kept for the median.
+    totals: Option<Vec<u64>>,
+}
+
+impl Summary {
+    /// Calories carried by the top elves.
+    pub fn top_calories(&self) -> u64 {
+        self.top.iter().map(|elf| elf.calories).sum()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (self.elves > 0).then(|| self.calories as f64 / self.elves as f64)
+    }
+
+    /// The median total, only known if the totals were kept (see `Elves::new`).
+    pub fn median(&self) -> Option<f64> {
+        let mut totals = self.totals.clone()?;
+        if totals.is_empty() {
+            return None;
+        }
+        totals.sort_unstable();
+        let middle = totals.len() / 2;
+        Some(if totals.len() % 2 == 1 {totals[middle] as f64} else {(totals[middle - 1] + totals[middle]) as f64 / 2.0})
+    }
+}
+
+impl fmt::Display for Summary {
+    /// A report of the top elves (by their position in the input), any ties with them and, when
+    /// known, the mean and median.
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Top {} of {} elves: {} calories", self.top.len(), self.elves, self.top_calories())?;
+        for (rank, elf) in self.top.iter().enumerate() {
+            writeln!(f, "{:>4}. elf {}: {}", rank + 1, elf.index, elf.calories)?;
+        }
+        if let (Some(last), ties @ 1..) = (self.top.last(), self.ties) {
+            writeln!(f, "{} more {} {} calories", ties, if ties == 1 {"elf carries"} else {"elves carry"}, last.calories)?;
+        }
+        if let (Some(mean), Some(median)) = (self.mean(), self.median()) {
+            writeln!(f, "Mean {:.1}, median {:.1}", mean, median)?;
+        }
+        Ok(())
+    }
+}
+
+/// Adds up each elf's calories a line at a time, keeping only the top elves (and, optionally,
+/// each elf's total), so the input needn't fit in memory.
+pub struct Elves {
+    count: usize,
+    // The top elves so far, with the one to drop next (fewest calories, then latest) at the top.
+    top: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+    ties: usize,
+    elves: usize,
+    calories: u64,
+    // The current elf's calories so far, None until a number's added after a blank line.
+    current: Option<u64>,
+    totals: Option<Vec<u64>>,
+}
+
+impl Elves {
+    /// Keep the top 'count' elves.  'keep_totals' keeps every elf's total as well, for the median.
+    pub fn new(count:usize, keep_totals:bool) -> Self {
+        Elves {count, top: BinaryHeap::with_capacity(count + 1), ties: 0, elves: 0, calories: 0, current: None,
+               totals: keep_totals.then(Vec::new)}
+    }
+
+    /// Add the next line of input, a number of calories or a blank line between elves.
+    pub fn line(&mut self, line:&Line) -> Result<()> {
+        if line.text.is_empty() {
+            let calories = self.current.take().unwrap_or(0);
+            self.end_elf(calories);
+        } else {
+            let calories = line.parse::<u64>(line.text)?;
+            self.current = Some(self.current.unwrap_or(0).checked_add(calories).ok_or_else(|| line.error("too many calories"))?);
+            self.calories = self.calories.checked_add(calories).ok_or_else(|| line.error("too many calories in total"))?;
+        }
+        Ok(())
+    }
+
+    fn end_elf(&mut self, calories:u64) {
+        self.elves += 1;
+        if let Some(totals) = &mut self.totals {
+            totals.push(calories);
+        }
+
+        let least = self.top.peek().map(|Reverse((least, _))| *least);
+        if self.top.len() < self.count {
+            self.top.push(Reverse((calories, Reverse(self.elves))));
+        } else if least == Some(calories) {
+            // The earlier elf keeps its place.
+            self.ties += 1;
+        } else if least.is_some_and(|least| calories > least) {
+            self.top.pop();
+            self.top.push(Reverse((calories, Reverse(self.elves))));
+            // The dropped elf is only tied with the top elves if another has as few calories.
+            let new_least = self.top.peek().map(|Reverse((least, _))| *least);
+            self.ties = if new_least == least {self.ties + 1} else {0};
+        }
+    }
+
+    /// The summary, once every line has been added.
+    pub fn finish(mut self) -> Summary {
+        // The last elf doesn't need a blank line after it.
+        if let Some(calories) = self.current.take() {
+            self.end_elf(calories);
+        }
+        let mut top = self.top.into_iter().map(|Reverse((calories, Reverse(index)))| Elf {index, calories}).collect::<Vec<Elf>>();
+        top.sort_by_key(|elf| (Reverse(elf.calories), elf.index));
+        Summary {elves: self.elves, calories: self.calories, top, ties: self.ties, totals: self.totals}
+    }
+}
+
+/// Summarise the elves in the input lines, keeping the top 'count'.
+pub fn parse_top(lines:&[String], count:usize) -> Result<Summary> {
+    let mut elves = Elves::new(count, false);
+    for line in Line::enumerate(1, lines) {
+        elves.line(&line)?;
+    }
+    Ok(elves.finish())
+}
+
+/// The top 3 elves in the input lines.
+pub fn parse(lines:&[String]) -> Result<Summary> {
+    parse_top(lines, Params::new(false).top)
+}
+
+/// Summarise the elves a line at a time from 'reader' ('name' describes it in errors), see
+/// `Elves::new`.
+pub fn read(reader:impl BufRead, name:&str, count:usize, keep_totals:bool) -> Result<Summary> {
+    let mut elves = Elves::new(count, keep_totals);
+    for (i, text) in reader.lines().enumerate() {
+        let text = text.map_err(|source| Error::Io {path: name.to_string(), source})?;
+        elves.line(&Line {day: 1, number: i + 1, text: &text})?;
+    }
+    Ok(elves.finish())
+}
+
+/// Calories carried by the elf carrying the most.
+pub fn part1(summary:&Summary) -> u64 {
+    summary.top.first().map_or(0, |elf| elf.calories)
+}
+
+/// Calories carried by the top 'params.top' elves (3 for the puzzle), an error if there are
+/// fewer elves than that.
+pub fn part2(summary:&Summary, params:&Params) -> Result<u64> {
+    if summary.elves < params.top {
+        return Err(Error::Solve {day: 1, message: format!("there are only {} elves, not {}", summary.elves, params.top)});
+    }
+    Ok(summary.top.iter().take(params.top).map(|elf| elf.calories).sum())
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(StreamSolver {
+        info: SolverInfo::new(crate::YEAR, 1, "day1", "Calorie Counting", [AnswerType::Integer, AnswerType::Integer])
+                  .with_params(Params::NAMES),
+        read: |reader, name, sample, overrides| {
+            let params = Params::new(sample).with(overrides)?;
+            // Part 1 needs the top elf, even if part 2 doesn't.
+            Ok((read(reader, name, params.top.max(1), false)?, params))
+        },
+        part1: |(input, _), _| Ok(part1(input).into()),
+        part2: |(input, params), _| Ok(part2(input, params)?.into()),
+    });
+}
@@ -0,0 +1,65 @@
+use crate::error::{Line, Result};
+use crate::grid::Grid;
+use crate::registry::Registry;
+use crate::scan;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Instructions as (x offset, number of cycles).
+pub type Input = Vec<(i32, u32)>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    let (add, noop) = ("addx", "noop");
+    let (add_cycles, noop_cycles) = (2, 1);
+    let mut instructions = Vec::new();
+    for line in Line::enumerate(10, lines) {
+        instructions.push(match line.text {
+            text if text.starts_with(add) => {
+                let (offset,) = scan!(line, "addx {}", i32)?;
+                (offset, add_cycles)}
+            text if text == noop => {(0, noop_cycles)}, // Just have 'noop' change x by 0
+            _ => {return Err(line.error("unexpected instruction"));}
+        });
+    }
+    Ok(instructions)
+}
+
+// Run the program, returning the signal strength and the CRT display.
+fn run(instructions:&Input) -> (i32, Grid<bool>) {
+    let (special_offset, special_modulo) = (20, 40);
+    let mut cycles = 0;
+    let mut x = 1;
+    let mut next_capture = special_offset;
+    let mut signal_strength = 0;
+    let mut pixels = Vec::new();
+    for (x_offset, instruction_cycles) in instructions {
+        for _i in 0..*instruction_cycles {
+            pixels.push({x-1..=x+1}.contains(&(cycles % 40)));
+
+            cycles += 1;
+            if cycles == next_capture {
+                signal_strength += next_capture * x;
+                next_capture += special_modulo;
+            }
+        }
+        x += x_offset;
+    }
+    let display = Grid::from_fn(40, pixels.len().div_ceil(40), |x, y| pixels.get(y * 40 + x).copied().unwrap_or(false));
+    (signal_strength, display)
+}
+
+pub fn part1(instructions:&Input) -> i32 {
+    run(instructions).0
+}
+
+pub fn part2(instructions:&Input) -> Grid<bool> {
+    run(instructions).1
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 10, "day10", "Cathode-Ray Tube", [AnswerType::Integer, AnswerType::Picture]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input).into()),
+    });
+}
@@ -0,0 +1,169 @@
+use std::collections::VecDeque;
+
+use crate::error::{end_of_input, Error, Result};
+use crate::params::Overrides;
+use crate::parse::blocks;
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Puzzle parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// Rounds for part 1, where worry levels are divided by 'relief' (at least 1) after each inspection.
+    pub rounds: u32,
+    pub relief: u64,
+    /// Rounds for part 2, with no relief.
+    pub part2_rounds: u32,
+}
+
+impl Params {
+    pub const NAMES:&'static [&'static str] = &["rounds", "relief", "part2_rounds"];
+
+    /// The defaults for the sample or real input.
+    pub fn new(_sample:bool) -> Self {
+        Params {rounds: 20, relief: 3, part2_rounds: 10000}
+    }
+
+    /// The parameters, with any overrides.
+    pub fn with(self, overrides:&Overrides) -> Result<Self> {
+        let relief = match overrides.get(11, "relief", self.relief)? {
+            0 => return Err(Error::Param {day: 11, name: "relief".to_string(), message: "must be at least 1".to_string()}),
+            relief => relief,
+        };
+        Ok(Params {rounds: overrides.get(11, "rounds", self.rounds)?,
+                   relief,
+                   part2_rounds: overrides.get(11, "part2_rounds", self.part2_rounds)?})
+    }
+}
+
+pub struct Monkey {
+    pub id: u32,
+    pub items: Vec<u64>,
+    pub operation: Box<dyn Fn(u64) -> u64>,
+    pub test:      u64,
+    pub true_monkey: u32,
+    pub false_monkey: u32,
+}
+
+pub type Input = Vec<Monkey>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    // Each monkey's notes are a block of lines, with one field on each.
+    let patterns = ["Monkey {}:", "  Starting items: {}", "  Operation: new = {}",
+                    "  Test: divisible by {}", "    If true: throw to monkey {}", "    If false: throw to monkey {}"];
+    // Operations: 'new = A * B', A-> num or old, A-> num or old.
+
+    let mut monkeys = Vec::<Monkey>::new();
+    // Where each monkey throws to, checked once all the monkeys are known.
+    let mut targets = Vec::new();
+
+    for block in blocks(11, lines).iter().filter(|block| !block.lines.is_empty()) {
+        block.check_len(patterns.len())?;
+        let note = |i:usize| {
+            let line = block.line(i, &format!("'{}'", patterns[i].split("{}").next().unwrap().trim()))?;
+            let [field] = line.scan(patterns[i])?;
+            Ok::<_, Error>((line, field))
+        };
+
+        let (line, monkey) = note(0)?;
+        let monkey = line.parse::<u32>(monkey)?;
+        if monkey as usize != monkeys.len() {
+            // Check that they are sequential.
+            return Err(line.error(format!("expected monkey {}", monkeys.len())));
+        }
+
+        let (line, items) = note(1)?;
+        let items = line.list::<u64>(items, ", ")?;
+
+        let (line, operation) = note(2)?;
+        let operation:Box::<dyn Fn(u64) -> u64> = match operation.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["old", "*", "old"] => {Box::new(|x| {x*x})},
+            ["old", "+", "old"] => {Box::new(|x| {x+x})},
+            ["old", "+", operand] => {
+                let operand = line.parse::<u64>(operand)?;
+                Box::new(move |x| {x+operand})
+            },
+            ["old", "*", operand] => {
+                let operand = line.parse::<u64>(operand)?;
+                Box::new(move |x| {x*operand})
+            }
+            _ => {return Err(line.error_at(operation, "unexpected operation"));}
+        };
+
+        let (line, test) = note(3)?;
+        let test = line.parse::<u64>(test)?;
+        if test == 0 {
+            return Err(line.error("can't test divisibility by 0"));
+        }
+
+        let (line, true_monkey) = note(4)?;
+        targets.push((line, true_monkey));
+        let true_monkey = line.parse::<u32>(true_monkey)?;
+
+        let (line, false_monkey) = note(5)?;
+        targets.push((line, false_monkey));
+        let false_monkey = line.parse::<u32>(false_monkey)?;
+
+        monkeys.push(Monkey{id:monkey, items, operation, test, true_monkey, false_monkey});
+    }
+
+    if let Some((line, target)) = targets.iter().find(|(line, target)| line.parse::<usize>(target).unwrap_or(0) >= monkeys.len()) {
+        return Err(line.error_at(target, "no such monkey"));
+    }
+    if monkeys.len() < 2 {
+        return Err(end_of_input(11, lines, "at least two monkeys"));
+    }
+    Ok(monkeys)
+}
+
+// Level of monkey business after the given number of rounds.
+fn monkey_business(monkeys:&Input, rounds:u32, relief:u64) -> u64 {
+    // Get the items, so they're mutable.
+    let mut divisor = 1;
+    let mut monkey_items = Vec::<VecDeque::<u64>>::new();
+    let mut inspections = vec![0;monkeys.len()];
+    for current_monkey in monkeys.iter() {
+        divisor *= current_monkey.test;
+        monkey_items.push(VecDeque::<u64>::new());
+        for i in current_monkey.items.iter() {
+            monkey_items[current_monkey.id as usize].push_back(*i);
+        }
+    }
+
+    for _round in 0..rounds {
+        for current_monkey in monkeys.iter() {
+            for _ in 0..monkey_items[current_monkey.id as usize].len() {
+                inspections[current_monkey.id as usize] += 1;
+                let mut worry = monkey_items[current_monkey.id as usize].pop_front().unwrap();
+                worry = (current_monkey.operation)(worry);
+                worry /= relief;
+                if worry.is_multiple_of(current_monkey.test) {
+                    monkey_items[current_monkey.true_monkey as usize].push_back(worry % divisor);
+                } else {
+                    monkey_items[current_monkey.false_monkey as usize].push_back(worry % divisor);
+                }
+            }
+        }
+    }
+    inspections.sort();
+    inspections.reverse();
+    inspections[0..2].iter().product()
+}
+
+pub fn part1(monkeys:&Input, params:&Params) -> u64 {
+    monkey_business(monkeys, params.rounds, params.relief)
+}
+
+pub fn part2(monkeys:&Input, params:&Params) -> u64 {
+    monkey_business(monkeys, params.part2_rounds, 1)
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 11, "day11", "Monkey in the Middle", [AnswerType::Integer, AnswerType::Integer])
+                  .with_params(Params::NAMES),
+        parse: |lines, sample, overrides| Ok((parse(lines)?, Params::new(sample).with(overrides)?)),
+        part1: |(input, params), _| Ok(part1(input, params).into()),
+        part2: |(input, params), _| Ok(part2(input, params).into()),
+    });
+}
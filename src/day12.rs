@@ -0,0 +1,61 @@
+use crate::error::{end_of_input, Error, Result};
+use crate::grid::Grid;
+use crate::registry::Registry;
+use crate::search::{bfs, Search};
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+pub struct Input {
+    /// Heights, 'a' = 0.
+    pub grid: Grid<u32>,
+    /// (x, y) of 'S'.
+    pub start: (usize, usize),
+    /// (x, y) of 'E'.
+    pub destination: (usize, usize),
+}
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    let map = Grid::parse(12, lines, "a height", |c| matches!(c, 'a'..='z' | 'S' | 'E').then_some(c))?;
+    let start = map.position(|c| *c == 'S').ok_or_else(|| end_of_input(12, lines, "a start position 'S'"))?;
+    let destination = map.position(|c| *c == 'E').ok_or_else(|| end_of_input(12, lines, "a destination 'E'"))?;
+    let grid = map.map(|c| match c {
+        'S' => 0,
+        'E' => 'z' as u32 - 'a' as u32,
+        _ => *c as u32 - 'a' as u32,
+    });
+    Ok(Input {grid, start, destination})
+}
+
+// Search back from the destination (so part 2 can stop at the closest 'a'), stepping to
+// neighbours that could climb to the current position.
+fn search_back(input:&Input, is_goal:impl FnMut(&(usize, usize)) -> bool) -> Search<(usize, usize)> {
+    let grid = &input.grid;
+    let climbable = |location:&(usize, usize)| {
+        let location = *location;
+        grid.neighbours4(location).filter(move |neighbour| grid[*neighbour] + 1 >= grid[location])
+                                  .map(|neighbour| (neighbour, 1))
+    };
+    bfs(&climbable, [input.destination], is_goal)
+}
+
+fn no_route(from:&str) -> Error {
+    Error::Solve {day: 12, message: format!("no route from {} to the destination", from)}
+}
+
+/// Fewest steps from the start to the destination, an error if it can't be reached.
+pub fn part1(input:&Input) -> Result<u64> {
+    search_back(input, |location| *location == input.start).goal_cost().ok_or_else(|| no_route("the start"))
+}
+
+/// Fewest steps from any of the lowest points to the destination, an error if none can reach it.
+pub fn part2(input:&Input) -> Result<u64> {
+    search_back(input, |location| input.grid[*location] == 0).goal_cost().ok_or_else(|| no_route("a lowest point"))
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 12, "day12", "Hill Climbing Algorithm", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input)?.into()),
+        part2: |input, _| Ok(part2(input)?.into()),
+    });
+}
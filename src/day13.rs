@@ -0,0 +1,132 @@
+use crate::error::{Line, Result};
+use crate::parse::blocks;
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Packets, with the blank separator lines removed.
+pub type Input = Vec<String>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    // Pairs of packets, separated by blank lines.
+    let mut packets = Vec::new();
+    let pairs = blocks(13, lines);
+    for block in &pairs {
+        block.check_len(2)?;
+        block.line(1, "a packet")?;
+    }
+    for line in pairs.iter().flat_map(|block| &block.lines) {
+        check_packet(line)?;
+        packets.push(line.text.to_string());
+    }
+    Ok(packets)
+}
+
+// Check that the packet is a well formed list of lists and numbers, e.g. '[1,[2,[]],3]'.
+fn check_packet(line:&Line) -> Result<()> {
+    // Parse the list or number starting at 'i', returning where it ends.
+    fn value(line:&Line, i:usize) -> Result<usize> {
+        let text = line.text;
+        match text[i..].chars().next() {
+            Some('[') if text[i + 1..].starts_with(']') => Ok(i + 2),
+            Some('[') => {
+                let mut i = i + 1;
+                loop {
+                    i = value(line, i)?;
+                    match text[i..].chars().next() {
+                        Some(',') => i += 1,
+                        Some(']') => return Ok(i + 1),
+                        Some(_) => return Err(line.error_at(&text[i..], "expected ',' or ']'")),
+                        None => return Err(line.error("unexpected end of packet")),
+                    }
+                }
+            },
+            Some('0'..='9') => {
+                let end = text[i..].find(|c:char| !c.is_ascii_digit()).map_or(text.len(), |n| i + n);
+                line.parse::<u32>(&text[i..end])?;
+                Ok(end)
+            },
+            Some(_) => Err(line.error_at(&text[i..], "unexpected character")),
+            None => Err(line.error("unexpected end of packet")),
+        }
+    }
+    if !line.text.starts_with('[') {
+        return Err(line.error("packet must be a list"));
+    }
+    let end = value(line, 0)?;
+    if end != line.text.len() {
+        return Err(line.error_at(&line.text[end..], "unexpected text after packet"));
+    }
+    Ok(())
+}
+
+// Returns true if the packets are in the right order, they must be well formed (see
+// 'check_packet').
+fn compare_list(left_string:String, right_string:String) -> bool {
+    let left_input = left_string.chars().collect::<Vec::<char>>();
+    let right_input = right_string.chars().collect::<Vec::<char>>();
+
+    let get_first_digit = |input:&[char]| {input.iter().take_while(|c| c.is_ascii_digit()).collect::<String>().parse::<u32>().unwrap_or(0)};
+    let skip_first_digit = |input:&[char]| {input.iter().skip_while(|c| c.is_ascii_digit()).collect::<String>()};
+
+    for ((l_idx, l), (r_idx, r)) in std::iter::zip(left_input.iter().enumerate(), right_input.iter().enumerate()) {
+        match (l, r) {
+            ('[','[') => {},
+            (',',',') => {},
+            (i,j) if i.is_ascii_digit() && j.is_ascii_digit() => {
+                let left_num = get_first_digit(&left_input[l_idx..]);
+                let right_num = get_first_digit(&right_input[l_idx..]);
+                if left_num < right_num {
+                    return true;
+                } else if left_num > right_num {
+                    return false;
+                }
+                else {
+                    return compare_list(left_input[l_idx..].iter().skip_while(|c| c.is_ascii_digit()).collect(),
+                                        right_input[l_idx..].iter().skip_while(|c| c.is_ascii_digit()).collect());
+                }
+            },
+            // If left is not a list, only need to compare the first value
+            (i,'[') if i.is_ascii_digit() => {
+                let left_num = get_first_digit(&left_input[l_idx..]);
+                return compare_list(format!("{}]{}",left_num, skip_first_digit(&left_input[(l_idx+1)..])),
+                                     right_input[(r_idx+1)..].iter().collect());
+
+            },
+            // If right is not a list, only need to compare the first value
+            ('[',j) if j.is_ascii_digit() => {
+                let right_num = get_first_digit(&right_input[l_idx..]);
+                return compare_list(left_input[(l_idx+1)..].iter().collect(),
+                                    format!("{}]{}",right_num, skip_first_digit(&right_input[(r_idx+1)..])));
+            },
+            (']',']') => {},
+            (']',_) => {return true}, // Left finished early
+            (_,']') => {return false},
+            // Well formed packets that match so far continue the same way, so there's nothing else.
+            _ => {return false},
+        }
+    }
+    true
+}
+
+pub fn part1(packets:&Input) -> u32 {
+    // 'parse' checked the packets are in pairs.
+    packets.chunks_exact(2).enumerate().filter(|(_, pair)| compare_list(pair[0].to_string(), pair[1].to_string()))
+           .map(|(index, _)| index as u32 + 1).sum()
+}
+
+pub fn part2(packets:&Input) -> u32 {
+    // Each divider's (1-based) position once sorted, counting the packets before it.
+    let before = |marker:&str| packets.iter().filter(|packet| compare_list(packet.to_string(), marker.to_string())).count();
+    let first_dividor = before("[[2]]") + 1;
+    let second_dividor = before("[[6]]") + 2;
+    (first_dividor * second_dividor) as u32
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 13, "day13", "Distress Signal", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input).into()),
+    });
+}
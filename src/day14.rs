@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+
+use crate::error::{Line, Result};
+use crate::grid::Grid;
+use crate::params::Overrides;
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Puzzle parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// Where the sand pours in from.
+    pub source: (u32, u32),
+}
+
+impl Params {
+    pub const NAMES:&'static [&'static str] = &["source_x", "source_y"];
+
+    /// The defaults for the sample or real input.
+    pub fn new(_sample:bool) -> Self {
+        Params {source: (500, 0)}
+    }
+
+    /// The parameters, with any overrides.
+    pub fn with(self, overrides:&Overrides) -> Result<Self> {
+        Ok(Params {source: (overrides.get(14, "source_x", self.source.0)?,
+                           overrides.get(14, "source_y", self.source.1)?)})
+    }
+}
+
+/// Points of rock in the cave, (x, y).
+pub type Input = HashSet<(u32, u32)>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    // Read lines of rock (x,y) -> (x,y)
+    // x = distance to right, y = distance down
+
+    // Just represent the cave as a 'hash set' of points, rather than a grid. Once settled, sand
+    // behaves like rock.
+    let mut cave = HashSet::<(u32,u32)>::new();
+
+    for line in Line::enumerate(14, lines) {
+        // Convert the line to a list of coordinates.
+        let coordinates = line.text.split(" -> ").map(|point| {
+            let (x, y) = point.split_once(',').ok_or_else(|| line.error_at(point, "expected x,y"))?;
+            Ok((line.parse::<u32>(x)?, line.parse::<u32>(y)?))
+        }).collect::<Result<Vec<(u32,u32)>>>()?;
+
+        let mut coord_it = coordinates.iter().peekable();
+        while let Some(coord) = coord_it.next() {
+            if let Some(next) = coord_it.peek()
+            {
+                let either_order_range = |a,b| {std::cmp::min(a,b)..=std::cmp::max(a,b)};
+                for x in either_order_range(coord.0, next.0) {
+                    for y in either_order_range(coord.1, next.1) {
+                        cave.insert((x,y));
+                    }
+                }
+            }
+        }
+    }
+    Ok(cave)
+}
+
+// Number of grains of sand that come to rest, with or without a floor below the lowest rock.
+fn pour_sand(rock:&Input, source:(u32, u32), floor:bool) -> u32 {
+    // sand = '+', 1 unit at a time
+    // sand moves 'down', then diagonally 'down left'-> 'down right' keeps moving until blocked.
+    // Stop when sand falls greater than lowest rock.
+    let mut max_depth = rock.iter().fold(source.1, |s, d| {std::cmp::max(s, d.1)}) as usize;
+
+    if floor {
+        max_depth += 1;
+    }
+
+    // Sand can't spread sideways further than it falls, so that's all the cave needs to cover,
+    // with x measured from its left edge (which may be left of x=0).
+    let left = source.0 as usize as isize - max_depth as isize - 1;
+    let right = rock.iter().fold(source.0, |s, d| {std::cmp::max(s, d.0)}) as usize + max_depth + 2;
+    let mut cave = Grid::new((right as isize - left) as usize, max_depth + 1, false);
+    for (x, y) in rock {
+        // Rock further left can't be reached.
+        if let Ok(x) = usize::try_from(*x as isize - left) {
+            cave[(x, *y as usize)] = true;
+        }
+    }
+
+    let source = (max_depth + 1, source.1 as usize);
+    let mut grains = 0;
+    let mut sand_position = source;
+    let mut keep_going = true;
+    while keep_going {
+
+        let offsets = [(0,1), (-1,1), (1,1)];
+        let next_position = offsets.iter()
+                                   .map(|(dx, dy)| (sand_position.0 as isize + dx, sand_position.1 as isize + dy))
+                                   .find(|(x, y)| cave.get(*x, *y) != Some(&true));
+        let mut new_grain = false;
+        if let Some((x, y)) = next_position {
+            sand_position = (x as usize, y as usize);
+
+            if floor {
+                // Create a new grain if it hit the max depth allowed.
+                new_grain = sand_position.1 == max_depth;
+            }
+        } else {
+            new_grain = true;
+        }
+
+        if sand_position == source {
+            // Second part, if the end point is the start, then stop
+            keep_going = false;
+        }
+
+        if new_grain {
+            // If the sand 'hits the bottom'
+            grains += 1; // Count the grains of sand.
+            cave[sand_position] = true;
+            sand_position = source;
+        }
+
+        if sand_position.1 == max_depth {
+            keep_going = false;
+        }
+    }
+
+    grains
+}
+
+pub fn part1(cave:&Input, params:&Params) -> u32 {
+    pour_sand(cave, params.source, false)
+}
+
+pub fn part2(cave:&Input, params:&Params) -> u32 {
+    pour_sand(cave, params.source, true)
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 14, "day14", "Regolith Reservoir", [AnswerType::Integer, AnswerType::Integer])
+                  .with_params(Params::NAMES),
+        parse: |lines, sample, overrides| Ok((parse(lines)?, Params::new(sample).with(overrides)?)),
+        part1: |(input, params), _| Ok(part1(input, params).into()),
+        part2: |(input, params), _| Ok(part2(input, params).into()),
+    });
+}
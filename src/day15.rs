@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use crate::error::{Line, Result};
+use crate::interval::{Interval, IntervalSet};
+use crate::params::Overrides;
+use crate::point::Point2;
+use crate::registry::Registry;
+use crate::scan;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Sensor and closest beacon positions, (sensor, beacon).
+pub type Input = Vec<(Point2<i32>, Point2<i32>)>;
+
+/// Puzzle parameters, the sample uses a much smaller row and search area.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// Row to count the positions that can't contain a beacon in (part 1).
+    pub row: i32,
+    /// The beacon's x and y are both in 0..=search_area (part 2).
+    pub search_area: i32,
+}
+
+impl Params {
+    pub const NAMES:&'static [&'static str] = &["row", "search_area"];
+
+    /// The defaults for the sample or real input.
+    pub fn new(sample:bool) -> Self {
+        if sample {Params {row: 10, search_area: 20}} else {Params {row: 2000000, search_area: 4000000}}
+    }
+
+    /// The parameters, with any overrides.
+    pub fn with(self, overrides:&Overrides) -> Result<Self> {
+        Ok(Params {row: overrides.get(15, "row", self.row)?,
+                   search_area: overrides.get(15, "search_area", self.search_area)?})
+    }
+}
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    Line::enumerate(15, lines).map(|line| {
+        let (sx, sy, bx, by) = scan!(line, "Sensor at x={}, y={}: closest beacon is at x={}, y={}", i32, i32, i32, i32)?;
+        Ok((Point2::new(sx, sy), Point2::new(bx, by)))
+    }).collect()
+}
+
+// (sensor, distance to the closest beacon)
+fn sensor_beacon_distance(info:&Input) -> Vec<(Point2<i32>,i32)> {
+    info.iter().map(|(sensor, beacon)| (*sensor, sensor.manhattan(*beacon))).collect()
+}
+
+fn no_beacon((sensor, distance):(Point2<i32>,i32), position:Point2<i32>) -> bool {
+    sensor.manhattan(position) <= distance
+}
+
+/// Number of positions in the row that can't contain a beacon.
+pub fn part1(info:&Input, params:&Params) -> u64 {
+    let row = params.row;
+    // The part of the row within each sensor's distance of its beacon.
+    let covered = sensor_beacon_distance(info).into_iter().filter_map(|(sensor, distance)| {
+        let half_width = i64::from(distance) - i64::from(sensor.y).abs_diff(i64::from(row)) as i64;
+        Interval::new(i64::from(sensor.x) - half_width, i64::from(sensor.x) + half_width)
+    }).collect::<IntervalSet>();
+    let beacons_in_row = info.iter().map(|(_, beacon)| *beacon).filter(|beacon| beacon.y == row).collect::<HashSet<Point2<i32>>>();
+    covered.len() - beacons_in_row.iter().filter(|beacon| covered.contains(i64::from(beacon.x))).count() as u64
+}
+
+/// Tuning frequency of the only possible beacon position in the search area.
+pub fn part2(info:&Input, params:&Params) -> u64 {
+    let max_search_area = params.search_area;
+    let beacon_positions = info.iter().map(|(_, beacon)| *beacon).collect::<HashSet<Point2<i32>>>();
+    let sensor_beacon_distance = sensor_beacon_distance(info);
+
+    // Test all points at a distance+1 from the closest beacon of each sensor.
+    let mut test_positions = Vec::<Point2<i32>>::new();
+
+    for (sensor, distance) in &sensor_beacon_distance {
+        let check_distance = distance + 1;
+        for i in 0..check_distance {
+            // Walk each side of the diamond, rotating the offset a quarter turn each time.
+            let mut offset = Point2::new(i, check_distance - i);
+            for _ in 0..4 {
+                test_positions.push(*sensor + offset);
+                offset = offset.rotate_right();
+            }
+        }
+    }
+
+    let mut result:u64 = 0;
+    for test_position in test_positions.iter().filter(|p| {0..=max_search_area}.contains(&p.x) && {0..=max_search_area}.contains(&p.y) ) {
+        if sensor_beacon_distance.iter().all(|sbd| {!no_beacon(*sbd, *test_position)})
+            && !beacon_positions.contains(test_position) {
+                // Increase if within the 'no beacon' zone and it's not a beacon position.
+                result = test_position.x as u64 * 4000000 + test_position.y as u64;
+                break; // Expect 1 result, so break out of the loop
+        }
+    }
+    result
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 15, "day15", "Beacon Exclusion Zone", [AnswerType::Integer, AnswerType::Integer])
+                  .with_params(Params::NAMES),
+        parse: |lines, sample, overrides| Ok((parse(lines)?, Params::new(sample).with(overrides)?)),
+        part1: |(input, params), _| Ok(part1(input, params).into()),
+        part2: |(input, params), _| Ok(part2(input, params).into()),
+    });
+}
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::cancel::Cancel;
+use crate::error::{Error, Line, Result};
+use crate::params::Overrides;
+use crate::registry::Registry;
+use crate::reporter;
+use crate::search::all_pairs;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Puzzle parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// Minutes before the volcano erupts, working alone (part 1).
+    pub minutes: u32,
+    /// Minutes left after teaching the elephant (part 2).
+    pub part2_minutes: u32,
+    /// Valve to start at.
+    pub start: String,
+}
+
+impl Params {
+    pub const NAMES:&'static [&'static str] = &["minutes", "part2_minutes", "start"];
+
+    /// The defaults for the sample or real input.
+    pub fn new(_sample:bool) -> Self {
+        Params {minutes: 30, part2_minutes: 26, start: "AA".to_string()}
+    }
+
+    /// The parameters, with any overrides.
+    pub fn with(self, overrides:&Overrides) -> Result<Self> {
+        Ok(Params {minutes: overrides.get(16, "minutes", self.minutes)?,
+                   part2_minutes: overrides.get(16, "part2_minutes", self.part2_minutes)?,
+                   start: overrides.get(16, "start", self.start)?})
+    }
+}
+
+pub struct Input {
+    pub valve_flow_rates: HashMap<String, u32>,
+    /// Valves reachable from each valve.
+    pub tunnel_links: HashMap<String, Vec<String>>,
+}
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    // Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+
+    let mut valve_flow_rates:HashMap<String,u32> = HashMap::new();
+    let mut tunnel_links = HashMap::new();
+    let mut all_tunnels = Vec::new();
+    for line in Line::enumerate(16, lines) {
+        // The grammar depends on the number of tunnels.
+        let pattern = if line.text.contains("tunnels") {"Valve {} has flow rate={}; tunnels lead to valves {}"}
+                      else {"Valve {} has flow rate={}; tunnel leads to valve {}"};
+        let [valve, rate, tunnels] = line.scan(pattern)?;
+        valve_flow_rates.insert(valve.to_string(), line.parse::<u32>(rate)?);
+
+        let exit_tunnels = tunnels.split(", ").collect::<Vec<&str>>();
+        tunnel_links.insert(valve.to_string(), exit_tunnels.iter().map(|w| w.to_string()).collect::<Vec<String>>());
+        all_tunnels.extend(exit_tunnels.into_iter().map(|tunnel| (line, tunnel)));
+    }
+
+    if let Some((line, tunnel)) = all_tunnels.iter().find(|(_, tunnel)| !valve_flow_rates.contains_key(*tunnel)) {
+        return Err(line.error_at(tunnel, "no such valve"));
+    }
+    Ok(Input {valve_flow_rates, tunnel_links})
+}
+
+// The parameters, which must start at one of the input's valves.
+fn parse_params(input:&Input, sample:bool, overrides:&Overrides) -> Result<Params> {
+    let params = Params::new(sample).with(overrides)?;
+    if !input.valve_flow_rates.contains_key(&params.start) {
+        return Err(Error::Param {day: 16, name: "start".to_string(), message: format!("no such valve {}", params.start)});
+    }
+    Ok(params)
+}
+
+// Visit as many valves as possible.
+// For each unvisited valve:
+//     - Move to the valve
+//     - Open the valve, update the 'final' value based on the time remaining when the valve is
+//     opened
+//
+//     Return the total.
+//
+// Gives up (returning the total so far) once 'cancel' is cancelled.
+#[allow(clippy::too_many_arguments)]
+fn total_flow(cancel:&Cancel, rates:&HashMap<String, u32>,
+              shortest: &HashMap<(String,String),u32>,
+              save_result: &mut Vec::<(u32, HashSet::<String>)>,
+              location:String, remaining_valves: &HashSet::<String>, time_remaining:u32, current_total:u32, watermark:u32) -> (u32,HashSet::<String>)  {
+    // If 'watermark' is set to 0, don't save.  Otherwise save any result that's larger than
+    // the mark (to use for subsequent searches).
+    if 0 != watermark && current_total >= watermark {save_result.push((current_total, remaining_valves.clone()));}
+
+    if 0 == time_remaining || cancel.is_cancelled() {
+        return (current_total,remaining_valves.clone());
+    }
+    if remaining_valves.is_empty() {
+        (current_total, remaining_valves.clone())
+    } else {
+          let remaining_rates = rates.iter().filter(|(k,_d)| remaining_valves.contains(*k)).collect::<Vec<(&String,&u32)>>();
+
+          remaining_rates.iter().fold((0, remaining_valves.clone()),  |max, (d,rate) | {
+            let path = {
+                // 'max_pressure' checked every valve can be reached.
+                let distance = &shortest[&(location.clone(), d.to_string())];
+                if time_remaining > *distance {
+                    let mut after_remove = remaining_valves.clone();
+                    after_remove.remove(*d);
+                    let new_accumulation = *rate * (time_remaining - distance - 1);
+                    total_flow(cancel, rates, shortest, save_result, d.to_string(),
+                                        &after_remove, time_remaining - distance - 1, current_total + new_accumulation, watermark)
+                } else {
+                    (current_total, remaining_valves.clone())
+                }
+            };
+            if max.0 > path.0 {
+                (max.0, max.1)
+            } else {
+                path
+            }
+        }
+        )
+    }
+}
+
+// Maximum pressure released in the time available, optionally with the help of an elephant.
+fn max_pressure(input:&Input, start:&str, max_time:u32, elephant:bool, cancel:&Cancel) -> Result<u32> {
+    // Get the shortest distance between each pair of Valves with a non-zero flow rate.
+    // Never turn on a valve with a zero flow rate.
+    // Keep a set of valves that have been turned on
+    // Keep a set of valves that are off (non-zero flow rate).
+    let valve_flow_rates = &input.valve_flow_rates;
+    let tunnel_links = &input.tunnel_links;
+    let mut unopen_valves = HashSet::new();
+
+    for (valve, rate) in valve_flow_rates {
+        if *rate > 0 {
+            unopen_valves.insert(valve.to_string());
+        }
+    }
+
+    // Only the valves worth opening, and the start, are ever visited.
+    let starting_point = start.to_string();
+    let mut valves = unopen_valves.iter().cloned().collect::<Vec<String>>();
+    valves.push(starting_point.to_string());
+    let tunnels = |valve:&String| tunnel_links[valve].iter().map(|next| (next.to_string(), 1)).collect::<Vec<(String, u64)>>();
+    let reachable = all_pairs(&tunnels, tunnel_links.keys().cloned().collect::<Vec<String>>().as_slice());
+    let shortest_paths = reachable.into_iter()
+                                  .filter(|((src, dst), _)| valves.contains(src) && valves.contains(dst))
+                                  .map(|(pair, distance)| (pair, distance as u32))
+                                  .collect::<HashMap<(String,String),u32>>();
+    // Every valve worth opening must be reachable from the start and from each other.
+    valves.sort();
+    for (src, dst) in valves.iter().flat_map(|src| unopen_valves.iter().map(move |dst| (src, dst))) {
+        if src != dst && !shortest_paths.contains_key(&(src.clone(), dst.clone())) {
+            return Err(Error::Solve {day: 16, message: format!("valve {} can't be reached from valve {}", dst, src)});
+        }
+    }
+
+    let mut save_result = Vec::new();
+
+    // Find the initial 'best' result for the given time
+    let flow_result = total_flow(cancel, valve_flow_rates, &shortest_paths, &mut save_result,
+                                    starting_point.to_string(), &unopen_valves, max_time, 0, 0);
+
+    if elephant {
+        save_result = Vec::new();
+        // Using the 'left over' valves, find the best 'elephant' result (this is the 'minimum'
+        // that the second helper should be able to cover).
+        let elephant_flow_result = total_flow(cancel, valve_flow_rates, &shortest_paths, &mut save_result,
+                                              starting_point.to_string(), &flow_result.1, max_time, 0, 0);
+
+        // Now re-run to find all results at least as good as the second run.
+        save_result = Vec::new();
+        let _ = total_flow(cancel, valve_flow_rates, &shortest_paths, &mut save_result,
+                           starting_point.to_string(), &unopen_valves, max_time, 0, std::cmp::max(1, elephant_flow_result.0));
+
+        let mut total_max = 0;
+        let mut subset = HashMap::new();
+
+        // Get a unique set of unopened valves, picking the 'best' if there are duplicates.
+        // This could be done as part of the 'save'.
+        for (total_for_save, search_set) in &save_result {
+            let mut sorted_set = search_set.iter().cloned().collect::<Vec<String>>();
+            sorted_set.sort();
+            let current_size = *subset.entry(sorted_set.clone()).or_insert(total_for_save);
+            if current_size < total_for_save {
+                *subset.get_mut(&sorted_set).unwrap() = total_for_save;
+            }
+        }
+
+        let subsets = subset.len() as u64;
+        for (i, (search_set, total_for_save)) in subset.into_iter().enumerate() {
+            reporter::update("valve set", i as u64, Some(subsets));
+            // For each remainder, find the best total.
+            let other_result = total_flow(cancel, valve_flow_rates, &shortest_paths, &mut Vec::new(),
+                                         starting_point.to_string(),
+                                         &search_set.iter().cloned().collect::<HashSet::<String>>(),
+                                         max_time, *total_for_save, 0);
+            total_max = std::cmp::max(total_max, other_result.0);
+        }
+
+        cancel.check()?;
+        Ok(total_max)
+    } else {
+        cancel.check()?;
+        Ok(flow_result.0)
+    }
+}
+
+/// Most pressure that can be released, 'params.start' must be one of the valves.
+pub fn part1(input:&Input, params:&Params, cancel:&Cancel) -> Result<u32> {
+    max_pressure(input, &params.start, params.minutes, false, cancel)
+}
+
+/// Most pressure that can be released with the help of an elephant.
+pub fn part2(input:&Input, params:&Params, cancel:&Cancel) -> Result<u32> {
+    max_pressure(input, &params.start, params.part2_minutes, true, cancel)
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 16, "day16", "Proboscidea Volcanium", [AnswerType::Integer, AnswerType::Integer])
+                  .with_params(Params::NAMES),
+        parse: |lines, sample, overrides| {
+            let input = parse(lines)?;
+            let params = parse_params(&input, sample, overrides)?;
+            Ok((input, params))
+        },
+        part1: |(input, params), cancel| Ok(part1(input, params, cancel)?.into()),
+        part2: |(input, params), cancel| Ok(part2(input, params, cancel)?.into()),
+    });
+}
@@ -0,0 +1,218 @@
+use crate::error::{end_of_input, Error, Line, Result};
+use crate::grid::Grid;
+use crate::params::Overrides;
+use crate::registry::Registry;
+use crate::reporter;
+use crate::{progress, trace};
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Puzzle parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// Rocks to drop for part 1.
+    pub rocks: u64,
+    /// Rocks to drop for part 2, too many to simulate.
+    pub part2_rocks: u64,
+}
+
+impl Params {
+    pub const NAMES:&'static [&'static str] = &["rocks", "part2_rocks"];
+
+    /// The defaults for the sample or real input.
+    pub fn new(_sample:bool) -> Self {
+        Params {rocks: 2022, part2_rocks: 1000000000000}
+    }
+
+    /// The parameters, with any overrides.
+    pub fn with(self, overrides:&Overrides) -> Result<Self> {
+        Ok(Params {rocks: overrides.get(17, "rocks", self.rocks)?,
+                   part2_rocks: overrides.get(17, "part2_rocks", self.part2_rocks)?})
+    }
+}
+
+/// Jet pattern, '<' or '>'.
+pub type Input = Vec<char>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    let line = Line::enumerate(17, lines).next().ok_or_else(|| end_of_input(17, lines, "the jet pattern"))?;
+    if let Some(position) = line.text.find(|c| c != '<' && c != '>') {
+        return Err(line.error_at(&line.text[position..], "expected '<' or '>'"));
+    }
+    if line.text.is_empty() {
+        return Err(line.error("expected the jet pattern"));
+    }
+    Ok(line.text.chars().collect())
+}
+
+// Tetris type pieces, as they appear.  They start with 2 spaces on the left.
+const BLOCKS:[&str; 5] = ["####",
+                          ".#.\n###\n.#.",
+                          "..#\n..#\n###",
+                          "#\n#\n#\n#",
+                          "##\n##"];
+
+// Get tetris type pieces
+fn blocks() -> Vec<Grid<bool>> {
+    BLOCKS.iter().map(|block| {
+        let rows = block.lines().map(|row| row.chars().map(|c| c == '#').collect()).collect();
+        Grid::from_rows(rows).expect("blocks are rectangular")
+    }).collect()
+}
+
+// The top rows of the tower.
+fn tower_text(game_state:&Grid<bool>, rows:usize) -> String {
+    game_state.rows().rev().take(rows)
+              .map(|row| row.iter().map(|filled| if *filled {'#'} else {'.'}).collect::<String>() + "\n")
+              .collect()
+}
+
+// Returns 'true' if the block for the given height/offset overlaps. False otherwise.  The tower
+// is stored from the bottom up.
+fn check_intersection(game_state:&Grid<bool>, block:&Grid<bool>, block_height:isize, block_offset:isize) -> bool
+{
+    // Check edges and bottom.
+    if block_offset < 0 || block_offset as usize + block.width() > game_state.width() || block_height < 0 {
+        return true;
+    }
+    block.iter().any(|((x, y), filled)| {
+        *filled && game_state.get(block_offset + x as isize, block_height + (block.height() - y - 1) as isize) == Some(&true)
+    })
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum State {
+    NewBlock,
+    Jet,
+    MoveDown,
+    Stopped,
+}
+
+// Drop blocks, returning (block number, tower height) after each block.
+fn drop_blocks(jets:&Input, blocks_to_test:usize) -> Vec<(usize, usize)> {
+    let blocks = blocks();
+    let mut current_game_state = State::NewBlock;
+
+    let gap_width:usize = 7;
+    let mut game_state = Grid::new(gap_width, 0, false);
+
+    let mut block_height_pairs = Vec::<(usize, usize)>::new();
+
+    // Start with a 'floor'
+    let mut current_block = &blocks[0];
+    let mut x_offset:isize = 2;
+    let mut movement = jets.iter().cycle(); // Characters should go forever
+
+    for current_block_number in 0..blocks_to_test {
+        if current_block_number % 100 == 0 {
+            reporter::update("rock", current_block_number as u64, Some(blocks_to_test as u64));
+        }
+        let mut block_height = game_state.height() + 3;
+
+        while current_game_state != State::Stopped {
+            match current_game_state {
+                State::NewBlock => {current_block = &blocks[current_block_number % blocks.len()];
+                    x_offset = 2; // Blocks start 2 to the right.
+                    current_game_state = State::Jet;
+                },
+                State::Jet => {
+                    // Parse has checked the pattern isn't empty, and is only '<' and '>'.
+                    let next_move = movement.next().unwrap_or(&'<');
+                    let next_offset = if *next_move == '>' {1} else {-1};
+                    if !check_intersection(&game_state, current_block, block_height as isize, x_offset + next_offset) {
+                        x_offset += next_offset;
+                    }
+                    current_game_state = State::MoveDown;
+                },
+                State::MoveDown => {
+                    if check_intersection(&game_state, current_block, block_height as isize - 1, x_offset) {
+                        current_game_state = State::Stopped;
+                    } else {
+                        block_height -= 1;
+                        current_game_state = State::Jet;
+                    }
+                },
+                State::Stopped => {},
+            }
+        }
+
+        // Store the block
+        for ((x, y), filled) in current_block.iter() {
+            let game_height = block_height + current_block.height() - y - 1;
+            while game_height >= game_state.height() {
+                game_state.push_row(vec![false; gap_width]);
+            }
+            if *filled {
+                game_state[(x + x_offset as usize, game_height)] = true;
+            }
+        }
+        block_height_pairs.push((current_block_number, game_state.height()));
+        trace!("Block {}, height {}, top of the tower:\n{}", current_block_number + 1, game_state.height(), tower_text(&game_state, 4));
+        current_game_state = State::NewBlock;
+    }
+
+    block_height_pairs
+}
+
+pub fn part1(jets:&Input, params:&Params) -> u64 {
+    drop_blocks(jets, params.rocks as usize).last().map_or(0, |(_, height)| *height as u64)
+}
+
+pub fn part2(jets:&Input, params:&Params) -> Result<u64> {
+    let block_height_pairs = drop_blocks(jets, 10000);
+
+    // Find a location where the sequence of height changes repeats.
+    // Get an arbitrary string (long enough to have confidence that it's unique)
+    // Find a subsequent location of the string.
+    // Find offsets that line up with the 'final block number', query for the hight at that
+    // point.
+    // Use the calculated deltas to determine what the result would be at the final block.
+    let mut sequence = Vec::<usize>::new();
+    let mut last = 0;
+    for block_height in &block_height_pairs {
+        sequence.push(block_height.1 - last);
+        last = block_height.1;
+    }
+
+    let search_offset = 443;
+    let search_size = 500; // Arbitrary, just needs to be smaller than the repetition, but big enough for confidence.
+    let search_splice = sequence[search_offset..(search_offset+search_size)].to_vec();
+
+    let no_repeat = || Error::Solve {day: 17, message: "the tower's height doesn't repeat".to_string()};
+    let mut next_match = search_offset + 1;
+    next_match =  next_match + sequence[next_match..].windows(search_splice.len()).position(|window| window == search_splice)
+                                                     .ok_or_else(no_repeat)?;
+
+    let last_block_number:u64 = params.part2_rocks;
+    let sequence_repetition_length = (next_match - search_offset) as u64;
+    let starting_point = last_block_number % sequence_repetition_length;
+
+    // 'block 1' is at index '0'
+    let height = |blocks:u64| match blocks {
+        0 => Ok(0),
+        _ => block_height_pairs.get(blocks as usize - 1).map(|(_, height)| *height as u64).ok_or_else(no_repeat),
+    };
+    let base_height = height(starting_point)?;
+    let repetition_height_increment = height(starting_point + 2 * sequence_repetition_length)? -
+                                      height(starting_point + sequence_repetition_length)?;
+
+    // Manual runs:
+    // 318 @ 200
+    // 2941 @ 1900
+    // 5564 @ 3600
+    // 1542941176480
+    progress!("Height repeats every {} blocks, adding {}, from a height of {} after {} blocks",
+              sequence_repetition_length, repetition_height_increment, base_height, starting_point);
+    (last_block_number / sequence_repetition_length).checked_mul(repetition_height_increment)
+        .and_then(|height| height.checked_add(base_height))
+        .ok_or_else(|| Error::Solve {day: 17, message: "the tower is too tall".to_string()})
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 17, "day17", "Pyroclastic Flow", [AnswerType::Integer, AnswerType::Integer])
+                  .with_params(Params::NAMES),
+        parse: |lines, sample, overrides| Ok((parse(lines)?, Params::new(sample).with(overrides)?)),
+        part1: |(input, params), _| Ok(part1(input, params).into()),
+        part2: |(input, params), _| Ok(part2(input, params)?.into()),
+    });
+}
@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use crate::error::{Line, Result};
+use crate::point::Point3;
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Positions of the lava cubes.
+pub type Input = Vec<Point3<i32>>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    let mut cubes = Vec::new();
+    for line in Line::enumerate(18, lines) {
+        let position = line.text.split(',').map(|l| line.parse::<i32>(l)).collect::<Result<Vec<i32>>>()?;
+        let [x, y, z] = position[..] else {
+            return Err(line.error("expected x,y,z"));
+        };
+        cubes.push(Point3::new(x, y, z));
+    }
+    Ok(cubes)
+}
+
+// Returns the set of lava points, offset so there are no zeros, and the maximum extent.
+fn lava_points(cubes:&Input) -> (HashSet<Point3<i32>>, Point3<i32>) {
+    let mut lava_points = HashSet::new();
+    let mut max = Point3::ORIGIN;
+    for position in cubes {
+        // Offsetting the points, so there are no zeros
+        let new_point = *position + Point3::new(1, 1, 1);
+        max = Point3::new(std::cmp::max(max.x, new_point.x+1),
+                          std::cmp::max(max.y, new_point.y+1),
+                          std::cmp::max(max.z, new_point.z+1));
+        lava_points.insert(new_point);
+    }
+    (lava_points, max)
+}
+
+pub fn part1(cubes:&Input) -> u64 {
+    let (lava_points, _) = lava_points(cubes);
+    let touching = |a:&Point3<i32>,b:&Point3<i32>| a.manhattan(*b) == 1;
+
+    let mut total_sides = 6 * cubes.len();
+    for cube in &lava_points {
+        total_sides -= &lava_points.iter().filter(|x| touching(x,cube)).count();
+    }
+    total_sides as u64
+}
+
+// Input, next set of 'air' points to check.
+// Output, new set of 'air' points and number of touching lava cubes.
+// Finish when there are no new 'air' points.
+fn increment_flood_fill(flooded: &mut HashSet::<Point3<i32>>,
+                        lava_points: &HashSet::<Point3<i32>>,
+                        search_points: &HashSet::<Point3<i32>>,
+                        max:Point3<i32>) -> (HashSet::<Point3<i32>>, u32) {
+    let mut new_search_points = HashSet::<Point3<i32>>::new();
+
+    let mut lava_surfaces = 0;
+    for look in search_points.iter() {
+        for offset in Point3::AXES {
+            let check_point = *look + offset;
+            if lava_points.contains(&check_point) {
+                lava_surfaces += 1;
+            } else if flooded.contains(&check_point) {
+                // Don't do anything.
+            } else {
+                if check_point.x <= max.x && check_point.y <= max.y && check_point.z <= max.z
+                   && check_point.x >= 0 && check_point.y >= 0 && check_point.z >= 0
+                {
+                    new_search_points.insert(check_point);
+                }
+            }
+        }
+        // Add the point to 'flooded' so it isn't checked again.
+        flooded.insert(*look);
+    }
+    (new_search_points, lava_surfaces)
+}
+
+pub fn part2(cubes:&Input) -> u64 {
+    let (lava_points, max) = lava_points(cubes);
+
+    let mut flooded = HashSet::new();
+    let mut search_points = HashSet::new();
+    let mut last_lava_touch_count;
+    let mut total_lava_surface = 0;
+    flooded.insert(Point3::ORIGIN);
+    search_points.insert(Point3::ORIGIN);
+
+    while !search_points.is_empty() {
+        (search_points, last_lava_touch_count) = increment_flood_fill(&mut flooded, &lava_points, &search_points, max);
+        total_lava_surface += last_lava_touch_count;
+    }
+
+    total_lava_surface as u64
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 18, "day18", "Boiling Boulders", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input).into()),
+    });
+}
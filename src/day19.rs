@@ -0,0 +1,163 @@
+use crate::cancel::Cancel;
+use crate::error::{Line, Result};
+use crate::params::Overrides;
+use crate::registry::Registry;
+use crate::reporter;
+use crate::scan;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Puzzle parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// Minutes to open geodes in, for part 1.
+    pub minutes: u32,
+    /// Minutes for part 2, which only uses the first few blueprints.
+    pub part2_minutes: u32,
+    pub part2_blueprints: usize,
+}
+
+impl Params {
+    pub const NAMES:&'static [&'static str] = &["minutes", "part2_minutes", "part2_blueprints"];
+
+    /// The defaults for the sample or real input.
+    pub fn new(_sample:bool) -> Self {
+        Params {minutes: 24, part2_minutes: 32, part2_blueprints: 3}
+    }
+
+    /// The parameters, with any overrides.
+    pub fn with(self, overrides:&Overrides) -> Result<Self> {
+        Ok(Params {minutes: overrides.get(19, "minutes", self.minutes)?,
+                   part2_minutes: overrides.get(19, "part2_minutes", self.part2_minutes)?,
+                   part2_blueprints: overrides.get(19, "part2_blueprints", self.part2_blueprints)?})
+    }
+}
+
+/// Robot costs for each blueprint, [robot][resource] for robots ore, clay, obsidian, geode and
+/// resources ore, clay, obsidian.
+pub type Input = Vec<Vec<Vec<u32>>>;
+
+// Pick a blueprint
+// ore, clay, obsidian
+// Number of opened geodes after 24 minutes
+//
+// 1 ore collecting robuts
+//
+// each robot can colect 1 of its resource type per minut
+// 1 minute for each robot of it's time to collect a resource
+// 1 minute for to construct any robot, and consumes resource at start of construction
+//
+// Can only use 1 blueprint
+//
+// Options:
+//   Do Nothing
+//   build ore robot
+//   build clay robot
+//   build obsidian robot
+//   build geod robot
+//
+//   Resources, ore, clay, obsidian, geod, time
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    let mut all_blueprints = Vec::new();
+    for line in Line::enumerate(19, lines) {
+        // Assume 1 blueprint per line
+        let (_id, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian) =
+            scan!(line, "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                         Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+                  u32, u32, u32, u32, u32, u32, u32)?;
+        all_blueprints.push(vec![vec![ore, 0, 0],
+                             vec![clay, 0, 0],
+                             vec![obsidian_ore, obsidian_clay, 0],
+                             vec![geode_ore, 0, geode_obsidian]]);
+    }
+    Ok(all_blueprints)
+}
+
+// Find the shortest time to make x geod robots, if time is < 24, then that's the best.
+// Gives up (returning the resources so far) once 'cancel' is cancelled.
+fn find_max_geods(cancel:&Cancel, blueprints:&[Vec<u32>], active_robots:&[u32], dont_build:&[bool], resources:&[u32], time_left:u32, current_max:u32) -> u32 {
+
+    #[derive(PartialEq, Clone, Copy)]
+    enum Robot { /*Ore = 0, Clay = 1, Obsidian = 2,*/ Geode = 3}
+
+    // Can build the robot if the resources are at lest the requirement for the blueprint
+    let can_build_robot = |blueprint, resource| {std::iter::zip(blueprint, resource).fold(true, |s,(b,r)| s && r >= b)};
+
+    if 0 == time_left || cancel.is_cancelled() {
+        // Return the resources for the geod robot.
+        resources[Robot::Geode as usize]
+    } else {
+        // Get the newly mined resources from previous robots.
+        let new_resources = std::iter::zip(active_robots, resources).map(|(a,b)| a+b).collect::<Vec<u32>>();
+
+        let mut max = current_max;
+
+        // Get the maximum of each option that exists.
+        // Assumes only 1 robot can be built per time step.
+        for (i, current_blueprint) in blueprints.iter().enumerate().rev() {
+            if i < Robot::Geode as usize &&
+               blueprints.iter().fold(0, |m,b| std::cmp::max(m,b[i])) <= active_robots[i] {
+                // Don't build too many robots of the same type.
+            } else if can_build_robot(current_blueprint, resources) && !dont_build[i]
+                // Don't keep searching if it's not possible for this path to reach the maximum
+                && current_max < resources[Robot::Geode as usize] + (time_left) * (active_robots[Robot::Geode as usize]) + ((time_left) * (time_left)/2)
+                {
+                // Use up the resource to build the robot.
+                let mut new_robots = active_robots.to_vec();
+                new_robots[i] += 1;
+
+                // Reduce the 'new' resources
+                let mut reduced_resources = std::iter::zip(&new_resources, current_blueprint).map(|(a,b)| a-b).collect::<Vec<u32>>();
+                reduced_resources.push(new_resources[Robot::Geode as usize]); // Geod isn't in the blueprint, so carry over.
+
+                max = std::cmp::max(max, find_max_geods(cancel, blueprints, &new_robots, &[false;4], &reduced_resources, time_left-1, max));
+            }
+        }
+
+        // Start with max if no robots are built.
+        let build_options =  blueprints.iter().map(|b| can_build_robot(b, resources)).collect::<Vec<bool>>();
+
+        std::cmp::max(max, find_max_geods(cancel, blueprints, active_robots, &build_options, &new_resources, time_left-1, max))
+    }
+}
+
+// Most geodes that can be opened with the blueprint in the given time.
+fn max_geodes(blueprints:&[Vec<u32>], time:u32, cancel:&Cancel) -> Result<u32> {
+    let robots    = vec![1, 0, 0, 0]; // Always start with 1 ore robot.
+    let resources = vec![0, 0, 0, 0];
+    let geodes = find_max_geods(cancel, blueprints, &robots, &[false;4], &resources, time, 0);
+    cancel.check()?;
+    Ok(geodes)
+}
+
+pub fn part1(all_blueprints:&Input, params:&Params, cancel:&Cancel) -> Result<u32> {
+    let mut quality = 0;
+    for (index, blueprints) in all_blueprints.iter().enumerate() {
+        reporter::update("blueprint", index as u64, Some(all_blueprints.len() as u64));
+        // Goal, most geodes in the time available (24 minutes)
+        let geodes = max_geodes(blueprints, params.minutes, cancel)?;
+        quality += geodes * (index as u32 + 1);
+    }
+    Ok(quality)
+}
+
+pub fn part2(all_blueprints:&Input, params:&Params, cancel:&Cancel) -> Result<u32> {
+    let mut result = 1;
+    let count = all_blueprints.len().min(params.part2_blueprints);
+    for (index, blueprints) in all_blueprints.iter().take(count).enumerate() {
+        reporter::update("blueprint", index as u64, Some(count as u64));
+        let geodes = max_geodes(blueprints, params.part2_minutes, cancel)?;
+        result *= geodes;
+    }
+    Ok(result)
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 19, "day19", "Not Enough Minerals", [AnswerType::Integer, AnswerType::Integer])
+                  .with_params(Params::NAMES),
+        parse: |lines, sample, overrides| Ok((parse(lines)?, Params::new(sample).with(overrides)?)),
+        part1: |(input, params), cancel| Ok(part1(input, params, cancel)?.into()),
+        part2: |(input, params), cancel| Ok(part2(input, params, cancel)?.into()),
+    });
+}
@@ -0,0 +1,299 @@
+use crate::compare::Rng;
+use crate::error::{Error, Line, Result};
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// The result of a round, for the player.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome { Lose = 0, Draw = 1, Win = 2 }
+
+impl Outcome {
+    pub const ALL:[Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+}
+
+/// The rules of a hand game like rock paper scissors: which hand beats which, what each hand and
+/// outcome scores, and how a strategy guide writes them.
+#[derive(Clone, Debug)]
+pub struct Game {
+    /// The hands' names, e.g. 'rock'.
+    pub hands: Vec<String>,
+    // beats[a][b] if hand a beats hand b.
+    beats: Vec<Vec<bool>>,
+    /// Score for playing each hand.
+    pub hand_scores: Vec<u32>,
+    /// Score for losing, drawing and winning (indexed by `Outcome`).
+    pub outcome_scores: [u32; 3],
+    /// The guide's symbol for each of the elf's hands (the first column).
+    pub elf_symbols: Vec<char>,
+    /// The guide's symbol for each of my hands (the second column, as read in part 1).
+    pub my_symbols: Vec<char>,
+    /// The guide's symbol for each outcome (the second column, as read in part 2).
+    pub outcome_symbols: [char; 3],
+}
+
+impl Game {
+    /// A game with the named hands, where `beats(a, b)` says whether hand a beats hand b.  Each
+    /// hand must beat some hand and lose to another, so that every outcome can be played.  Hands
+    /// score 1, 2, 3... and losing, drawing and winning 0, 3 and 6.  The elf's hands are written
+    /// 'A', 'B', 'C'..., mine the same number of letters up to 'Z' and the outcomes 'X', 'Y', 'Z'.
+    pub fn new(hands:&[&str], beats:impl Fn(usize, usize) -> bool) -> std::result::Result<Self, String> {
+        let n = hands.len();
+        let beats = (0..n).map(|a| (0..n).map(|b| beats(a, b)).collect()).collect::<Vec<Vec<bool>>>();
+        for a in 0..n {
+            if beats[a][a] {
+                return Err(format!("{} beats itself", hands[a]));
+            }
+            if let Some(b) = (0..n).find(|&b| beats[a][b] && beats[b][a]) {
+                return Err(format!("{} and {} beat each other", hands[a], hands[b]));
+            }
+            if !beats[a].iter().any(|&wins| wins) {
+                return Err(format!("{} doesn't beat any hand", hands[a]));
+            }
+            if !(0..n).any(|b| beats[b][a]) {
+                return Err(format!("no hand beats {}", hands[a]));
+            }
+        }
+        if n > 26 {
+            return Err(format!("{} hands is more than the guide's symbols", n));
+        }
+        let letters = |first:u8| (first..first + n as u8).map(char::from).collect();
+        Ok(Game {hands: hands.iter().map(|hand| hand.to_string()).collect(), beats,
+                 hand_scores: (1..=n as u32).collect(), outcome_scores: [0, 3, 6],
+                 elf_symbols: letters(b'A'), my_symbols: letters(b'Z' + 1 - n as u8), outcome_symbols: ['X', 'Y', 'Z']})
+    }
+
+    /// A game where each hand beats the hands an odd number of places before it (wrapping
+    /// around), e.g. the arithmetic `(me - elf) mod 3 == 1` win for rock paper scissors.  There
+    /// must be an odd number of hands.
+    pub fn cyclic(hands:&[&str]) -> std::result::Result<Self, String> {
+        let n = hands.len();
+        if n.is_multiple_of(2) {
+            return Err(format!("a cyclic game needs an odd number of hands, not {}", n));
+        }
+        Game::new(hands, |a, b| (a + n - b) % n % 2 == 1)
+    }
+
+    /// Rock paper scissors, with each hand beaten by the next one round the cycle.
+    pub fn rock_paper_scissors() -> Self {
+        let hands = ["rock", "paper", "scissors"];
+        let winning_hand = |hand| (hand + 1) % hands.len();
+        Game::new(&hands, |a, b| a == winning_hand(b)).unwrap()
+    }
+
+    /// Rock paper scissors lizard spock: scissors cuts paper, paper covers rock, rock crushes
+    /// lizard, lizard poisons spock, spock smashes scissors, scissors decapitates lizard, lizard
+    /// eats paper, paper disproves spock, spock vaporizes rock and rock crushes scissors.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Game::cyclic(&["rock", "paper", "scissors", "spock", "lizard"]).unwrap()
+    }
+
+    /// Use other symbols for the guide's columns (see the fields of the same names).
+    pub fn with_symbols(self, elf_symbols:&str, my_symbols:&str, outcome_symbols:&str) -> std::result::Result<Self, String> {
+        let symbols = |name:&str, text:&str, count:usize| {
+            let symbols = text.chars().collect::<Vec<char>>();
+            if symbols.len() != count {
+                Err(format!("expected {} {} symbols, found '{}'", count, name, text))
+            } else if symbols.iter().enumerate().any(|(i, c)| symbols[..i].contains(c)) {
+                Err(format!("the {} symbols '{}' aren't distinct", name, text))
+            } else {
+                Ok(symbols)
+            }
+        };
+        let n = self.hands.len();
+        Ok(Game {elf_symbols: symbols("elf", elf_symbols, n)?, my_symbols: symbols("hand", my_symbols, n)?,
+                 outcome_symbols: symbols("outcome", outcome_symbols, 3)?.try_into().unwrap(), ..self})
+    }
+
+    /// Use other scores, for each hand and for losing, drawing and winning.
+    pub fn with_scores(self, hand_scores:&[u32], outcome_scores:[u32; 3]) -> std::result::Result<Self, String> {
+        if hand_scores.len() != self.hands.len() {
+            return Err(format!("expected {} hand scores, found {}", self.hands.len(), hand_scores.len()));
+        }
+        Ok(Game {hand_scores: hand_scores.to_vec(), outcome_scores, ..self})
+    }
+
+    /// The outcome of playing hand 'me' against the elf's hand.
+    pub fn outcome(&self, me:usize, elf:usize) -> Outcome {
+        if self.beats[me][elf] {
+            Outcome::Win
+        } else if self.beats[elf][me] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// The hand to play against the elf's for the outcome: the best scoring one if there are
+    /// several (then the first).  Drawing plays the elf's hand.
+    pub fn hand_for(&self, elf:usize, outcome:Outcome) -> usize {
+        if outcome == Outcome::Draw {
+            return elf;
+        }
+        // The game's checks ensure every hand can be beaten and beats another.
+        (0..self.hands.len()).filter(|&me| self.outcome(me, elf) == outcome)
+                             .max_by_key(|&me| (self.hand_scores[me], std::cmp::Reverse(me))).unwrap()
+    }
+
+    /// My score for the round.
+    pub fn score(&self, me:usize, elf:usize) -> u32 {
+        self.hand_scores[me] + self.outcome_scores[self.outcome(me, elf) as usize]
+    }
+
+    /// Read a strategy guide, checking every symbol is one of the game's.  The second column need
+    /// only be valid as either a hand or an outcome, it's checked again by the part that uses it.
+    pub fn parse(&self, lines:&[String]) -> Result<Guide> {
+        let rounds = Line::enumerate(2, lines).map(|line| {
+            let [elf_string, me_string] = line.fields()?;
+            let symbol = |s:&str| {
+                let mut chars = s.chars();
+                chars.next().filter(|_| chars.next().is_none())
+            };
+            let elf = symbol(elf_string).and_then(|c| self.elf_symbols.iter().position(|&e| e == c))
+                          .ok_or_else(|| line.error_at(elf_string, format!("expected one of {}", String::from_iter(&self.elf_symbols))))?;
+            let second = symbol(me_string);
+            let hand = second.and_then(|c| self.my_symbols.iter().position(|&m| m == c));
+            let outcome = second.and_then(|c| self.outcome_symbols.iter().position(|&o| o == c)).map(|i| Outcome::ALL[i]);
+            if hand.is_none() && outcome.is_none() {
+                let mut expected = self.my_symbols.clone();
+                expected.extend(self.outcome_symbols.iter().filter(|c| !self.my_symbols.contains(c)));
+                return Err(line.error_at(me_string, format!("expected one of {}", String::from_iter(expected))));
+            }
+            Ok(Round {line: line.number, elf, hand, outcome})
+        }).collect::<Result<Vec<Round>>>()?;
+        Ok(Guide {rounds})
+    }
+
+    /// Total score, reading the guide's second column as the hand to play.
+    pub fn score_hands(&self, guide:&Guide) -> Result<u32> {
+        guide.rounds.iter().map(|round| {
+            let me = round.hand.ok_or_else(|| round.error("a hand", &self.my_symbols))?;
+            Ok(self.score(me, round.elf))
+        }).sum()
+    }
+
+    /// Total score, reading the guide's second column as the outcome to play for.
+    pub fn score_outcomes(&self, guide:&Guide) -> Result<u32> {
+        guide.rounds.iter().map(|round| {
+            let outcome = round.outcome.ok_or_else(|| round.error("an outcome", &self.outcome_symbols))?;
+            Ok(self.score(self.hand_for(round.elf, outcome), round.elf))
+        }).sum()
+    }
+}
+
+/// A round of the strategy guide: the elf's hand, and the second column read as my hand and as
+/// an outcome (None if the symbol isn't one).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Round {
+    /// The line number in the guide, for errors.
+    pub line: usize,
+    pub elf: usize,
+    pub hand: Option<usize>,
+    pub outcome: Option<Outcome>,
+}
+
+impl Round {
+    // The second column isn't what the part needed.
+    fn error(&self, wanted:&str, symbols:&[char]) -> Error {
+        Error::Parse {day: 2, line: self.line, column: None, text: String::new(),
+                      message: format!("expected {} ({})", wanted, String::from_iter(symbols))}
+    }
+}
+
+/// A strategy guide, read for a particular game.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guide {
+    pub rounds: Vec<Round>,
+}
+
+/// The game and its strategy guide.
+pub type Input = (Game, Guide);
+
+/// Read the guide for rock paper scissors.
+pub fn parse(lines:&[String]) -> Result<Input> {
+    let game = Game::rock_paper_scissors();
+    let guide = game.parse(lines)?;
+    Ok((game, guide))
+}
+
+pub fn part1((game, guide):&Input) -> Result<u32> {
+    game.score_hands(guide)
+}
+
+pub fn part2((game, guide):&Input) -> Result<u32> {
+    game.score_outcomes(guide)
+}
+
+/// The strategy guide for the arithmetic version, pairs of column indexes: elf ('ABC' -> 0..3),
+/// me ('XYZ' -> 0..3).
+pub type OldInput = Vec<(usize, usize)>;
+
+pub fn parse_old(lines:&[String]) -> Result<OldInput> {
+    Line::enumerate(2, lines).map(|line| {
+        let [elf_string, me_string] = line.fields()?;
+        let index = |choices:&str, s:&str| {
+            choices.find(s).filter(|_| s.len() == 1)
+                   .ok_or_else(|| line.error_at(s, format!("expected one of {}", choices)))
+        };
+        Ok((index("ABC", elf_string)?, index("XYZ", me_string)?))
+    }).collect()
+}
+
+// Original arithmetic implementation, kept as an alternate solver.
+fn score_old(guide:&OldInput, second_part:bool) -> u32 {
+    // Rock = 1, Paper = 2, Scissors = 3,
+    // Lose, draw, win: 0, 3, 6
+
+    let mut total_score = 0;
+    for (elf_index, me_index) in guide {
+        // Parse has checked the indexes are 0..3.
+        let elf = *elf_index as i8 + 1;
+        let (me, points);
+        if !second_part {
+            me = *me_index as i8 + 1;
+            points = match (elf, me) {
+                (elf, me) if (elf - me == 1) || (me - elf == 2) => {0},
+                (elf, me) if elf == me => {3},
+                (_, _) => {6},
+            };
+        } else {
+            points = *me_index as i8 * 3;
+            me = match (elf, points) {
+                (elf, 0) => {if elf != 1 {elf - 1} else {3}},
+                (elf, 3) => {elf},
+                (elf, _) => {(elf % 3) + 1},
+            };
+        }
+
+        total_score += points as u32 + me as u32;
+    }
+    total_score
+}
+
+pub fn part1_old(guide:&OldInput) -> u32 {
+    score_old(guide, false)
+}
+
+pub fn part2_old(guide:&OldInput) -> u32 {
+    score_old(guide, true)
+}
+
+/// A random guide of up to 20 rounds, to compare the solvers on.
+pub fn generate(rng:&mut Rng) -> Vec<String> {
+    (0..=rng.below(20)).map(|_| format!("{} {}", rng.choose(&['A', 'B', 'C']), rng.choose(&['X', 'Y', 'Z']))).collect()
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 2, "day2", "Rock Paper Scissors", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input)?.into()),
+        part2: |input, _| Ok(part2(input)?.into()),
+    });
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 2, "day2_old", "Rock Paper Scissors", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse_old(lines),
+        part1: |input, _| Ok(part1_old(input).into()),
+        part2: |input, _| Ok(part2_old(input).into()),
+    });
+    registry.register_generator(crate::YEAR, 2, generate);
+}
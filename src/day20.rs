@@ -0,0 +1,61 @@
+use crate::error::{end_of_input, Line, Result};
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// The encrypted file.
+pub type Input = Vec<i64>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    let numbers = Line::enumerate(20, lines).map(|line| line.parse::<i64>(line.text)).collect::<Result<Input>>()?;
+    // There must be a single zero to find the grove coordinates from.
+    if numbers.iter().filter(|n| **n == 0).count() != 1 {
+        return Err(end_of_input(20, lines, "exactly one 0"));
+    }
+    Ok(numbers)
+}
+
+// Sum of the grove coordinates after mixing.
+fn grove_coordinates(numbers:&Input, multiplier:i64, num_cycles:u32) -> i64 {
+    let mut code = Vec::new();
+
+    for (i, value) in numbers.iter().enumerate() {
+        code.push((i, value * multiplier));
+    }
+
+    let mut new_code = Vec::new();
+    for _cycle in 0..num_cycles {
+        new_code = code.clone();
+        for i in 0..code.len() {
+            let current_position = new_code.iter().position(|(original_index,_v)| *original_index == i).unwrap();
+            let (original_index, value) = new_code[current_position];
+            new_code.remove(current_position);
+            let new_position = (((current_position as i64 + value) % new_code.len() as i64) + new_code.len() as i64) % new_code.len() as i64;
+            new_code.insert(new_position as usize, (original_index, value));
+        }
+        code = new_code.clone();
+    }
+
+    let mut result:i64 = 0;
+    let zero_pos = new_code.iter().position(|(_original_index,v)| *v == 0).unwrap();
+    for search_index in [1000, 2000, 3000].iter() {
+        result += new_code[(zero_pos + search_index) % new_code.len()].1;
+    }
+    result
+}
+
+pub fn part1(numbers:&Input) -> i64 {
+    grove_coordinates(numbers, 1, 1)
+}
+
+pub fn part2(numbers:&Input) -> i64 {
+    grove_coordinates(numbers, 811589153, 10)
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 20, "day20", "Grove Positioning System", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input).into()),
+    });
+}
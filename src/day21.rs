@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use crate::error::{end_of_input, Error, Line, Result};
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Monkey jobs, 'name: job'.
+pub type Input = Vec<String>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    // Each monkey yells a number 'name: N', or the result of an operation 'name: a + b'.
+    let mut names = Vec::new();
+    let mut references = Vec::new();
+    for line in Line::enumerate(21, lines) {
+        let [name, job] = line.scan("{}: {}")?;
+        match job.split(' ').collect::<Vec<&str>>()[..] {
+            [number] => {line.parse::<i64>(number)?;},
+            [a, "+" | "-" | "*" | "/", b] => {references.push((line, a)); references.push((line, b));},
+            _ => {return Err(line.error_at(job, "unexpected job"));}
+        }
+        names.push(name);
+    }
+
+    if let Some((line, name)) = references.iter().find(|(_, name)| !names.contains(name)) {
+        return Err(line.error_at(name, "no such monkey"));
+    }
+    for name in ["root", "humn"] {
+        if !names.contains(&name) {
+            return Err(end_of_input(21, lines, &format!("monkey '{}'", name)));
+        }
+    }
+    Ok(lines.to_vec())
+}
+
+fn lookup(lines:&Input) -> HashMap<&str, &str> {
+    let mut lookup = HashMap::new();
+    for line in lines.iter() {
+        let elements = line.split(": ").collect::<Vec<&str>>();
+        lookup.insert(elements[0], elements[1]);
+    }
+    lookup
+}
+
+fn error(message:String) -> Error {
+    Error::Solve {day: 21, message}
+}
+
+// The result of an operation, or an error if it divides by zero or overflows.
+fn operate(a:i64, operation:&str, b:i64) -> Result<i64> {
+    let result = match operation {
+        "+" => a.checked_add(b),
+        "-" => a.checked_sub(b),
+        "*" => a.checked_mul(b),
+        _ => a.checked_div(b),
+    };
+    result.ok_or_else(|| error(format!("can't work out {} {} {}", a, operation, b)))
+}
+
+// 'depth' is the number of monkeys waiting on this one, more than there are means they're
+// waiting on each other.
+fn resolve_values(lookup:&HashMap::<&str,&str>, current_node:&str, depth:usize) -> Result<i64> {
+    if depth > lookup.len() {
+        return Err(error(format!("monkey '{}' is waiting on itself", current_node)));
+    }
+    // 'parse' checked every monkey exists, and their jobs.
+    let value = lookup[current_node];
+    let args = value.split(" ").collect::<Vec<&str>>();
+    if args.len() > 1 {
+        let a = resolve_values(lookup, args[0], depth + 1)?;
+        let b = resolve_values(lookup, args[2], depth + 1)?;
+        operate(a, args[1], b)
+    } else {
+        value.parse::<i64>().map_err(|_| error(format!("invalid number {}", value)))
+    }
+}
+
+fn resolve_inverse(lookup:&HashMap::<&str,&str>, lines: &[String], current_node:&str, depth:usize) -> Result<i64> {
+    // Find the monkey waiting on the node.
+    let (name, job) = lines.iter().filter_map(|l| l.split_once(": ")).find(|(_, job)| {
+        let args = job.split(' ').collect::<Vec<&str>>();
+        args.len() == 3 && (args[0] == current_node || args[2] == current_node)
+    }).ok_or_else(|| error(format!("no monkey is waiting on '{}'", current_node)))?;
+    if depth > lookup.len() {
+        return Err(error(format!("monkey '{}' is waiting on itself", name)));
+    }
+    let args = job.split(" ").collect::<Vec<&str>>();
+
+    let node_on_left = current_node == args[0];
+
+    let b = if node_on_left {
+        resolve_values(lookup, args[2], 0)?
+    } else {
+        resolve_values(lookup, args[0], 0)?
+    };
+
+    // If the line we're trying to find is 'root', then just return the 'other' side.
+    if name != "root" {
+        let a = resolve_inverse(lookup, lines, name, depth + 1)?;
+
+        if node_on_left {
+            // If the current node is on the left, of the operation
+            match args[1] {
+                "+" => operate(a, "-", b),
+                "-" => operate(a, "+", b),
+                "*" => operate(a, "/", b),
+                _ => operate(a, "*", b),
+            }
+        } else {
+            // If the current node is on the right, of the operation
+            match args[1] {
+                "+" => operate(a, "-", b),
+                "-" => operate(b, "-", a),
+                "*" => operate(a, "/", b),
+                _ => operate(b, "/", a),
+            }
+        }
+    } else {
+        Ok(b)
+    }
+}
+
+pub fn part1(lines:&Input) -> Result<i64> {
+    resolve_values(&lookup(lines), "root", 0)
+}
+
+pub fn part2(lines:&Input) -> Result<i64> {
+    resolve_inverse(&lookup(lines), lines, "humn", 0)
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 21, "day21", "Monkey Math", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input)?.into()),
+        part2: |input, _| Ok(part2(input)?.into()),
+    });
+}
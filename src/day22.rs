@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use crate::error::{end_of_input, Error, Result};
+use crate::grid::Grid;
+use crate::parse::sections;
+use crate::point::{Direction, Point2, Point3};
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Map { Void = 0, Empty = 1, Wall = 2}
+
+pub struct Input {
+    /// The map, padded with 'Void' to a whole number of cube faces in each direction.
+    pub map: Grid<Map>,
+    /// Path description, e.g. '10R5L5', alternating distances (that fit an i32) and turns.
+    pub movements: String,
+}
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    let [map_block, moves] = sections(22, lines, ["the map", "the move list"])?;
+    let mut map = Grid::parse_padded(22, &lines[..map_block.lines.len()], Map::Void, "a map tile", |c| match c {
+        ' ' => Some(Map::Void),
+        '.' => Some(Map::Empty),
+        '#' => Some(Map::Wall),
+        _ => None,
+    })?;
+
+    // The map must fold into a cube, with somewhere to start on the top row.
+    let points = map.iter().filter(|(_, m)| **m != Map::Void).count() as i32;
+    let width = cube_width(&map);
+    if points == 0 || points != 6 * width * width || !map.row(0).contains(&Map::Empty) {
+        return Err(end_of_input(22, lines, "a map that folds into a cube"));
+    }
+
+    // Size of the unfolded cube, e.g. 4x3 faces for the sample and 3x4 for the real input.
+    let faces = |size:usize| size.div_ceil(width as usize) * width as usize;
+    map.resize(faces(map.width()), faces(map.height()), Map::Void);
+
+    let line = moves.line(0, "the move list")?;
+    moves.check_len(1)?;
+    if let Some(position) = line.text.find(|c:char| !(c.is_ascii_digit() || c == 'L' || c == 'R')) {
+        return Err(line.error_at(&line.text[position..], "expected a distance, 'L' or 'R'"));
+    }
+    if !line.text.starts_with(|c:char| c.is_ascii_digit()) || line.text.contains("LR") || line.text.contains("RL") ||
+       line.text.contains("LL") || line.text.contains("RR") {
+        return Err(line.error("moves must alternate between a distance and a turn"));
+    }
+    for distance in line.text.split(['L', 'R']).filter(|distance| !distance.is_empty()) {
+        line.parse::<i32>(distance)?;
+    }
+    let movements = line.text.to_string();
+
+    Ok(Input {map, movements})
+}
+
+// Calculate the cube width from the input.
+fn cube_width(map:&Grid<Map>) -> i32 {
+    let points = map.iter().filter(|(_, m)| **m != Map::Void).count();
+    ((points/6) as f32).sqrt() as i32
+}
+
+// Step forward, wrapping around the flat map.
+fn next_step(map:&Grid<Map>, position:(usize, usize), heading:Direction) -> (usize, usize) {
+    let step = heading.offset::<isize>();
+    map.wrap(position.0 as isize + step.x, position.1 as isize + step.y)
+}
+
+pub fn part1(input:&Input) -> i32 {
+    let map = &input.map;
+    let movements = &input.movements;
+
+    // Get the initial position.
+    let mut position = (map.row(0).iter().position(|c| *c == Map::Empty).unwrap(), 0);
+    let mut heading = Direction::Right;
+
+    let mut movements_iter = movements.chars().peekable();
+    while movements_iter.peek().is_some() {
+        let mut distance = Vec::<char>::new();
+        while let Some(digit) = movements_iter.next_if(|c| c.is_ascii_digit())
+        {
+            distance.push(digit);
+        }
+
+        for _ in 0..distance.iter().collect::<String>().parse::<i32>().unwrap_or(0) {
+            let mut next_position = next_step(map, position, heading);
+            while map[next_position] == Map::Void {
+                // Find the first 'non empty' position (at worst, the current one).
+                next_position = next_step(map, next_position, heading);
+            }
+
+            if map[next_position] == Map::Empty {
+                position = next_position;
+            }
+        }
+
+        // 'parse' checked the distances are followed by turns.
+        match movements_iter.next() {
+            Some('R') => {heading = heading.turn_right();},
+            Some('L') => {heading = heading.turn_left();},
+            _ => {}
+        }
+    }
+
+    1000 * (position.1 as i32 + 1) + 4 * (position.0 as i32 + 1) + heading as i32
+}
+
+pub fn part2(input:&Input) -> Result<i32> {
+    // Flood fill
+    // If leaving a 'panel', update 'dir' and 'normal'
+    // Store each pixel in 3d with normal (normal, (x,y,z)) (there will be duplicates on edges).
+    let map = &input.map;
+    let movements = &input.movements;
+
+    let cube_width = cube_width(map);
+
+    let panel_pos = Point2::ORIGIN;
+    let grid_dir = Direction::Right.offset();
+
+    // Set the initial position in 3d.  Turning left is an anticlockwise rotation about the
+    // normal, which matches an anticlockwise rotation on the flat map (looking from above, z = -1).
+    let mut normal            = Point3::new(0,0,-1);
+    let mut position          = Point3::ORIGIN;
+    let mut current_direction = Point3::new(1,0,0);
+
+    // Hash: (normal), (position) = (Map, (x,y))
+    let mut real_cube = HashMap::new();
+
+    // Get the initial position in the flat image.
+    let grid_position = Point2::new(map.row(0).iter().position(|c| *c == Map::Empty).unwrap() as i32,0);
+
+    // Create the cube in 3d.  Maintain direction for both the current 3d point and the 2d input
+    // location.  Insert each input character into a map with the key being 'point + normal', also
+    // store the 2d grid (as it's needed at the end to calculate the result values).
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    fn build_cube(real_cube: &mut HashMap::<(Point3<i32>,Point3<i32>),(Map,Point2<i32>)>,
+                  grid:&Grid<Map>, grid_pos:Point2<i32>, panel_pos:Point2<i32>, current_grid_dir:Point2<i32>,
+                  position:Point3<i32>, normal:Point3<i32>, current_direction:Point3<i32>, width:i32) -> Result<()> {
+        if real_cube.get(&(normal,position)).is_none() {
+            if panel_pos.x >= 0 && panel_pos.y >= 0 && panel_pos.y < width && panel_pos.x < width
+            {
+                let tile = grid.get(grid_pos.x as isize, grid_pos.y as isize).copied().unwrap_or(Map::Void);
+                if tile == Map::Void {
+                    return Err(fold_error());
+                }
+                real_cube.insert((normal,position),(tile,grid_pos));
+                let mut new_grid_dir = current_grid_dir;
+                let mut new_direction = current_direction;
+                for _ in 0..4 {
+                    // Check each direction.
+                    let new_position = position + new_direction;
+                    let new_grid_pos = grid_pos + new_grid_dir;
+                    let new_panel_pos = panel_pos + new_grid_dir;
+
+                    build_cube(real_cube, grid, new_grid_pos, new_panel_pos, new_grid_dir,
+                               new_position, normal, new_direction, width)?;
+                    new_grid_dir = new_grid_dir.rotate_left();
+                    new_direction = new_direction.rotate_left(normal);
+                }
+            } else {
+                if grid.get(grid_pos.x as isize, grid_pos.y as isize).is_some_and(|tile| *tile != Map::Void) {
+                        // Only continue if the edge of the grid hasn't been reached.
+                        let new_position = position - current_direction; // Need to step back 1 in 3d
+                        let new_normal = current_direction;
+                        let new_direction = -normal;
+                        let new_panel_pos = Point2::new(grid_pos.x.rem_euclid(width), grid_pos.y.rem_euclid(width));
+                        // Switch sides and keep building.
+                        build_cube(real_cube, grid, grid_pos, new_panel_pos, current_grid_dir, new_position, new_normal, new_direction, width)?;
+                    }
+            }
+        }
+        Ok(())
+    }
+
+    build_cube(&mut real_cube, map, grid_position, panel_pos, grid_dir, position, normal, current_direction, cube_width)?;
+    // Every point of every face, or the map isn't a net of the cube.
+    if real_cube.len() != 6 * (cube_width * cube_width) as usize {
+        return Err(fold_error());
+    }
+
+    // Movement
+    position          = Point3::ORIGIN;
+
+    // Answer was too high (36541).
+    let mut movements_iter = movements.chars().peekable();
+    while movements_iter.peek().is_some() {
+        let mut distance      = Vec::<char>::new();
+
+        while let Some(digit) = movements_iter.next_if(|c| c.is_ascii_digit())
+        {
+            distance.push(digit);
+        }
+
+        for _ in 0..distance.iter().collect::<String>().parse::<i32>().unwrap_or(0) {
+            let mut new_normal          = normal;
+            let mut new_direction = current_direction;
+            let mut new_position = position + new_direction;
+
+            // Check if we need to move to a new surface.
+            if new_position.x < 0 || new_position.y < 0 || new_position.z < 0 ||
+               new_position.x >= cube_width || new_position.y >= cube_width || new_position.z >= cube_width {
+                new_normal = current_direction;
+                new_direction = -normal;
+                new_position = position; // reset the position.
+            }
+
+            if let Some((value,_grid_pos)) = real_cube.get(&(new_normal,new_position)) {
+                if *value != Map::Wall {
+                    current_direction = new_direction;
+                    normal = new_normal;
+                    position = new_position;
+                }
+            } else {
+                return Err(fold_error());
+            }
+
+        }
+
+        match movements_iter.next() {
+            Some('R') => {current_direction = current_direction.rotate_right(normal);},
+            Some('L') => {current_direction = current_direction.rotate_left(normal);},
+            _ => {}
+        }
+    }
+
+    // Get the heading
+    // Not keeping track of the 2d heading, so move in 3d.
+    // If forward falls of panel, then go in reverse.
+    let mut heading_value = 0;
+    if let Some((_value,grid_pos)) = real_cube.get(&(normal,position)) {
+        let mut grid_direction = Point2::ORIGIN;
+        if let Some((_,next_grid_pos)) = real_cube.get(&(normal,position + current_direction)) {
+            grid_direction = *next_grid_pos - *grid_pos;
+        } else if let Some((_,next_grid_pos)) = real_cube.get(&(normal,position - current_direction)) {
+            grid_direction = *grid_pos - *next_grid_pos;
+        }
+        // The 'heading' value is right = 0, down = 1, left = 2, up = 3.
+        if let Some(direction) = Direction::ALL.into_iter().find(|d| d.offset() == grid_direction) {
+            heading_value = direction as i32;
+        }
+
+    }
+
+    if let Some((_,grid_pos)) = real_cube.get(&(normal,position)) {
+        Ok((1000 * (grid_pos.y+1)) + (4 * (grid_pos.x+1)) + heading_value)
+    } else {
+        Err(fold_error())
+    }
+}
+
+fn fold_error() -> Error {
+    Error::Solve {day: 22, message: "the map doesn't fold into a cube".to_string()}
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 22, "day22", "Monkey Map", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input)?.into()),
+    });
+}
@@ -0,0 +1,137 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{end_of_input, Result};
+use crate::grid::{Grid, NEIGHBOURS8};
+use crate::point::{Direction, Point2};
+use crate::registry::Registry;
+use crate::trace;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Positions of the elves.
+pub type Input = Vec<Point2<i32>>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    let map = Grid::parse(23, lines, "'#' or '.'", |c| matches!(c, '#' | '.').then_some(c == '#'))?;
+    let elves = map.iter().filter(|(_, elf)| **elf).map(|((x, y), _)| Point2::new(x as i32, y as i32)).collect::<Input>();
+    if elves.is_empty() {
+        return Err(end_of_input(23, lines, "an elf"));
+    }
+    Ok(elves)
+}
+
+// Spread the elves out for up to 'max_rounds', returning their final positions and the first round
+// where no elf moved (0 if they were still moving).
+fn spread(initial_elves:&Input, max_rounds:i32) -> (Vec<Point2<i32>>, i32) {
+    let mut elves = initial_elves.clone();
+
+    let move_directions = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+    let mut move_direction_iter = move_directions.iter().cycle();
+
+    let mut final_round = 0;
+
+    trace!("Initial state:\n{}", points_text(&elves));
+
+    for round in 0..max_rounds {
+
+        let mut proposed_locations:HashMap::<Point2<i32>,u32> = HashMap::new();
+
+        let mut proposed_new_elves:Vec<Point2<i32>> = Vec::new();
+        // Where the elves are, to look around them without searching the whole list.
+        let occupied = elves.iter().copied().collect::<HashSet<Point2<i32>>>();
+
+        // Look for a spot.
+        for elf in &elves {
+            let mut elf_move_direction = move_direction_iter.clone();
+
+            // Stay put, unless there's somewhere to move to.
+            let mut proposed = *elf;
+
+            let no_other_elves = NEIGHBOURS8.iter().all(|(i, j)| !occupied.contains(&(*elf + Point2::new(*i as i32, *j as i32))));
+
+            if !no_other_elves {
+                for _ in 0..move_directions.len() {
+                    let look_dir = elf_move_direction.next().unwrap().offset();
+                    let mut other_elves = false;
+                    // The position in the direction, and the diagonals either side of it.
+                    for j in -1..=1 {
+                        other_elves |= occupied.contains(&(*elf + look_dir + look_dir.rotate_left() * j));
+                    }
+
+                    if !other_elves {
+                        proposed = *elf + look_dir;
+                        *proposed_locations.entry(proposed).or_insert(0) += 1;
+                        break;
+                    }
+                }
+            }
+            proposed_new_elves.push(proposed);
+        }
+
+
+        let mut new_elves:Vec<Point2<i32>> = Vec::new();
+        // Move if there was only one elf going there (you).
+        for (elf, new_position) in std::iter::zip(&elves, proposed_new_elves) {
+            if let Some(value) = proposed_locations.get(&new_position)
+            {
+                if *value == 1 {
+                    new_elves.push(new_position);
+                } else {
+                    new_elves.push(*elf);
+                }
+            } else {
+                // Elf didn't move.
+                new_elves.push(*elf);
+            }
+        }
+
+        elves = new_elves;
+
+        move_direction_iter.next().unwrap();
+        if proposed_locations.is_empty() {
+            final_round = round + 1_i32;
+            break;
+        }
+
+        trace!("End of round {}:\n{}", round + 1, points_text(&elves));
+    }
+
+    (elves, final_round)
+}
+
+// The smallest rectangle containing the points, ((min x, min y), (width, height)).
+fn bounds(points:&[Point2<i32>]) -> ((i32, i32), (i32, i32)) {
+    let min_x = points.iter().map(|p| p.x).min().unwrap();
+    let max_x = points.iter().map(|p| p.x).max().unwrap();
+    let min_y = points.iter().map(|p| p.y).min().unwrap();
+    let max_y = points.iter().map(|p| p.y).max().unwrap();
+    ((min_x, min_y), (max_x - min_x + 1, max_y - min_y + 1))
+}
+
+pub fn part1(elves:&Input) -> i32 {
+    let (elves, _) = spread(elves, 10);
+    let (_, (width, height)) = bounds(&elves);
+    width * height - elves.len() as i32
+}
+
+pub fn part2(elves:&Input) -> i32 {
+    spread(elves, 10000).1
+}
+
+// The points as a map, '#' for a point.
+fn points_text(points:&[Point2<i32>]) -> String {
+    let ((min_x, min_y), (width, height)) = bounds(points);
+    let mut output = Grid::new(width as usize, height as usize, false);
+    for point in points {
+        output[((point.x - min_x) as usize, (point.y - min_y) as usize)] = true;
+    }
+    format!("Offset, min (x,y) = {} {}\n{}", min_x, min_y, output.render(|elf| if *elf {'#'} else {'.'}))
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 23, "day23", "Unstable Diffusion", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input).into()),
+    });
+}
@@ -0,0 +1,132 @@
+use crate::cancel::Cancel;
+use crate::error::{end_of_input, Error, Result};
+use crate::grid::Grid;
+use crate::point::{Direction, Point2};
+use crate::registry::Registry;
+use crate::reporter;
+use crate::search::astar;
+use crate::{progress, trace};
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Blizzards moving in each direction, excluding the walls.
+pub struct Input {
+    pub left: Grid<bool>,
+    pub right: Grid<bool>,
+    pub up: Grid<bool>,
+    pub down: Grid<bool>,
+}
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    // Horizontal and vertical don't interact.
+    // for each step, check the next path.
+    // Horizontal repeats after 'x'
+    // Vertical repeats after 'y'
+    // Empty when both horizontal and vertical are empty
+    let map = Grid::parse(24, lines, "a blizzard, '.' or '#'", |c| matches!(c, '<' | '>' | '^' | 'v' | '.' | '#').then_some(c))?;
+    if map.width() < 3 || map.height() < 3 {
+        return Err(end_of_input(24, lines, "a valley surrounded by walls"));
+    }
+
+    // Everything inside the walls.
+    let blizzards = |direction| Grid::from_fn(map.width() - 2, map.height() - 2, |x, y| map[(x + 1, y + 1)] == direction);
+    Ok(Input {left: blizzards('<'), right: blizzards('>'), up: blizzards('^'), down: blizzards('v')})
+}
+
+fn check_is_empty(blizzards:&Input, Point2 {x, y}:Point2<i32>, time:i32) -> bool
+{
+    let y_size = blizzards.right.height() as i32;
+    let x_size = blizzards.right.width() as i32;
+    if x == x_size - 1 && y == y_size {
+        return true;
+    }
+    if x == 0 && y == -1 {
+        return true;
+    }
+    if x < 0 || y < 0 || x >= x_size || y >= y_size {
+        return false;
+    }
+    let (x, y, time) = (x as isize, y as isize, time as isize);
+    !(*blizzards.right.get_wrapping(x - time, y) ||
+      *blizzards.left.get_wrapping(x + time, y) ||
+      *blizzards.up.get_wrapping(x, y + time) ||
+      *blizzards.down.get_wrapping(x, y - time))
+}
+
+// Quickest time to get from 'src' to 'dst', leaving at 'start_time', None if there's no way
+// through.  The blizzards repeat, so the search only needs the time within their cycle.
+fn quickest(blizzards:&Input, src:Point2<i32>, dst:Point2<i32>, start_time:i32, cancel:&Cancel) -> Result<Option<i32>> {
+    let (width, height) = (blizzards.right.width() as i32, blizzards.right.height() as i32);
+    let period = width * height / gcd(width, height);
+    // Move in any direction, or wait.
+    let moves = [Direction::Right.offset(), Direction::Down.offset(), Point2::ORIGIN, Direction::Up.offset(), Direction::Left.offset()];
+    // Once cancelled there's nowhere to go, which ends the search.
+    let next = |(location, time):&(Point2<i32>, i32)| {
+        let (location, time) = (*location, (time + 1) % period);
+        let moves = if cancel.is_cancelled() {&moves[..0]} else {&moves[..]};
+        moves.iter().map(move |step| location + *step)
+                         .filter(move |new_location| check_is_empty(blizzards, *new_location, time))
+                         .map(move |new_location| ((new_location, time), 1))
+    };
+    let search = astar(&next, [(src, start_time % period)], |(location, _)| *location == dst,
+                       |(location, _)| location.manhattan(dst) as u64);
+    cancel.check()?;
+    Ok(search.goal_cost().map(|time| start_time + time as i32))
+}
+
+fn gcd(a:i32, b:i32) -> i32 {
+    if b == 0 {a} else {gcd(b, a % b)}
+}
+
+// Times at which the goal, start and then the goal again are reached, an error if there's no way
+// through the blizzards.
+fn goal_times(input:&Input, cancel:&Cancel) -> Result<(i32, i32, i32)> {
+    let no_way = || Error::Solve {day: 24, message: "there's no way through the blizzards".to_string()};
+    let (width, height) = (input.right.width() as i32, input.right.height() as i32);
+    let (start, goal) = (Point2::new(0, -1), Point2::new(width - 1, height));
+
+    trace!("Initial blizzards:\n{}", blizzard_text(input));
+    reporter::update("trip", 0, Some(3));
+    let first_goal_time = quickest(input, start, goal, 0, cancel)?.ok_or_else(no_way)?;
+    progress!("Reached the goal at minute {}", first_goal_time);
+    reporter::update("trip", 1, Some(3));
+    let second_goal_time = quickest(input, goal, start, first_goal_time, cancel)?.ok_or_else(no_way)?;
+    progress!("Back at the start at minute {}", second_goal_time);
+    reporter::update("trip", 2, Some(3));
+    let third_goal_time = quickest(input, start, goal, second_goal_time, cancel)?.ok_or_else(no_way)?;
+    progress!("Reached the goal again at minute {}", third_goal_time);
+
+    Ok((first_goal_time, second_goal_time, third_goal_time))
+}
+
+/// Fewest minutes to reach the goal, an error if there's no way through the blizzards.
+pub fn part1(input:&Input, cancel:&Cancel) -> Result<i32> {
+    Ok(goal_times(input, cancel)?.0)
+}
+
+/// Fewest minutes to reach the goal, go back for the snacks and reach the goal again.
+pub fn part2(input:&Input, cancel:&Cancel) -> Result<i32> {
+    Ok(goal_times(input, cancel)?.2)
+}
+
+// The blizzards as a map, with the number of blizzards where they overlap.
+fn blizzard_text(blizzards:&Input) -> String {
+    let directions = [(&blizzards.left, '<'), (&blizzards.right, '>'), (&blizzards.up, '^'), (&blizzards.down, 'v')];
+    let map = Grid::from_fn(blizzards.left.width(), blizzards.left.height(), |x, y| {
+        let here = directions.iter().filter(|(grid, _)| grid[(x, y)]).map(|(_, c)| *c).collect::<Vec<char>>();
+        match here[..] {
+            [] => '.',
+            [c] => c,
+            _ => char::from_digit(here.len() as u32, 10).unwrap(),
+        }
+    });
+    map.render(|c| *c)
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 24, "day24", "Blizzard Basin", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, cancel| Ok(part1(input, cancel)?.into()),
+        part2: |input, cancel| Ok(part2(input, cancel)?.into()),
+    });
+}
@@ -0,0 +1,71 @@
+use crate::error::{Error, Line, Result};
+use crate::answer::Answer;
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// SNAFU numbers, one per line.
+pub type Input = Vec<String>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    for line in Line::enumerate(25, lines) {
+        if let Some(position) = line.text.find(|c| !"=-012".contains(c)) {
+            return Err(line.error_at(&line.text[position..], "invalid SNAFU digit"));
+        }
+        if snafu_to_dec(line.text).is_none() {
+            return Err(line.error("SNAFU number is too large"));
+        }
+    }
+    Ok(lines.to_vec())
+}
+
+// The digit's value, parse has checked it's a SNAFU digit.
+fn snafu_char_to_dec(c:char) -> i64 {
+    match c {
+        '2' => {2},
+        '1' => {1},
+        '-' => {-1},
+        '=' => {-2},
+        _ => {0},
+    }
+}
+
+// None if it doesn't fit in an i64.
+fn snafu_to_dec(line:&str) -> Option<i64> {
+    line.chars().try_fold(0i64, |sum,c| sum.checked_mul(5)?.checked_add(snafu_char_to_dec(c)))
+}
+
+fn dec_to_snafu(input: i64) -> String {
+    // Convert to base 5, with digits from -2 to 2: a remainder of 3 or 4 is written as -2 or -1,
+    // carrying one to the next digit.
+    let mut remainder = input;
+    let mut snafu_digits = Vec::new();
+    while remainder != 0 {
+        let digit = remainder.rem_euclid(5);
+        snafu_digits.push(b"012=-"[digit as usize] as char);
+        remainder = remainder.div_euclid(5) + if digit > 2 {1} else {0};
+    }
+    if snafu_digits.is_empty() {
+        snafu_digits.push('0');
+    }
+    snafu_digits.iter().rev().collect()
+}
+
+pub fn part1(lines:&Input) -> Result<String> {
+    assert_eq!("1=11-2", dec_to_snafu(2022));
+    assert_eq!("1-0---0", dec_to_snafu(12345));
+    assert_eq!("1121-1110-1=0", dec_to_snafu(314159265));
+    let snafu_dec = lines.iter().try_fold(0i64, |sum,line| sum.checked_add(snafu_to_dec(line)?))
+                         .ok_or_else(|| Error::Solve {day: 25, message: "the sum is too large".to_string()})?;
+
+    Ok(dec_to_snafu(snafu_dec))
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 25, "day25", "Full of Hot Air", [AnswerType::Text, AnswerType::Empty]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input)?.into()),
+        // There is no second part for this puzzle.
+        part2: |_, _| Ok(Answer::None),
+    });
+}
@@ -0,0 +1,231 @@
+use std::fmt;
+
+use crate::error::{Error, Line, Result};
+use crate::params::Overrides;
+use crate::progress;
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Puzzle parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// Number of equal compartments in each rucksack (part 1).
+    pub compartments: usize,
+    /// Number of elves in each group (part 2).
+    pub group_size: usize,
+}
+
+impl Params {
+    pub const NAMES:&'static [&'static str] = &["compartments", "group_size"];
+
+    /// The defaults for the sample or real input.
+    pub fn new(_sample:bool) -> Self {
+        Params {compartments: 2, group_size: 3}
+    }
+
+    /// The parameters, with any overrides.
+    pub fn with(self, overrides:&Overrides) -> Result<Self> {
+        let positive = |name:&str, default:usize| match overrides.get(3, name, default)? {
+            0 => Err(Error::Param {day: 3, name: name.to_string(), message: "must be at least 1".to_string()}),
+            value => Ok(value),
+        };
+        Ok(Params {compartments: positive("compartments", self.compartments)?, group_size: positive("group_size", self.group_size)?})
+    }
+}
+
+/// An item's priority: 'a' to 'z' are 1 to 26 and 'A' to 'Z' 27 to 52.  None if it isn't an item.
+pub fn priority(item:char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(1 + item as u32 - 'a' as u32),
+        'A'..='Z' => Some(27 + item as u32 - 'A' as u32),
+        _ => None,
+    }
+}
+
+/// A set of items, a bit for each priority.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Items(u64);
+
+impl Items {
+    pub const EMPTY:Items = Items(0);
+    /// Every item, 'a' to 'Z'.
+    pub const ALL:Items = Items(((1 << 52) - 1) << 1);
+
+    /// Add the item, returning false if it isn't one.
+    pub fn insert(&mut self, item:char) -> bool {
+        priority(item).map(|priority| self.0 |= 1 << priority).is_some()
+    }
+
+    pub fn contains(&self, item:char) -> bool {
+        priority(item).is_some_and(|priority| self.0 & 1 << priority != 0)
+    }
+
+    pub fn union(self, other:Items) -> Items {
+        Items(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other:Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    /// The items not in 'other'.
+    pub fn difference(self, other:Items) -> Items {
+        Items(self.0 & !other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The items' priorities, lowest first.
+    pub fn priorities(&self) -> impl Iterator<Item = u32> + '_ {
+        (1..=52).filter(|priority| self.0 & 1 << priority != 0)
+    }
+
+    pub fn priority_sum(&self) -> u32 {
+        self.priorities().sum()
+    }
+
+    /// The items, in priority order.
+    pub fn items(&self) -> impl Iterator<Item = char> + '_ {
+        self.priorities().map(|priority| match priority {
+            1..=26 => char::from(b'a' + priority as u8 - 1),
+            _ => char::from(b'A' + priority as u8 - 27),
+        })
+    }
+}
+
+impl FromIterator<char> for Items {
+    /// The set of items, ignoring anything that isn't one.
+    fn from_iter<T: IntoIterator<Item = char>>(iter:T) -> Self {
+        let mut items = Items::EMPTY;
+        for item in iter {
+            items.insert(item);
+        }
+        items
+    }
+}
+
+impl fmt::Display for Items {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        self.items().try_for_each(|item| write!(f, "{}", item))
+    }
+}
+
+/// A rucksack's items, by compartment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    pub compartments: Vec<Items>,
+}
+
+impl Rucksack {
+    /// Everything in the rucksack.
+    pub fn items(&self) -> Items {
+        self.compartments.iter().fold(Items::EMPTY, |all, items| all.union(*items))
+    }
+
+    /// The items in every compartment.
+    pub fn common(&self) -> Items {
+        self.compartments.iter().fold(Items::ALL, |common, items| common.intersection(*items))
+    }
+}
+
+/// The rucksacks, and the size of the groups they're in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    pub rucksacks: Vec<Rucksack>,
+    pub group_size: usize,
+}
+
+impl Input {
+    /// Each group's rucksacks, an error if the last group isn't complete.
+    pub fn groups(&self) -> Result<impl Iterator<Item = &[Rucksack]>> {
+        let missing = (self.group_size - self.rucksacks.len() % self.group_size) % self.group_size;
+        if missing > 0 {
+            // Each rucksack is a line.
+            return Err(Error::Parse {day: 3, line: self.rucksacks.len() + 1, column: None, text: String::new(),
+                                     message: format!("unexpected end of input, expected {} more rucksack{} for the last group of {}",
+                                                      missing, if missing == 1 {""} else {"s"}, self.group_size)});
+        }
+        Ok(self.rucksacks.chunks(self.group_size))
+    }
+}
+
+/// Read the rucksacks, split into the parameters' compartments and groups.  Every compartment
+/// of a rucksack must be the same size (the groups are checked by `Input::groups`).
+pub fn parse_with(lines:&[String], params:&Params) -> Result<Input> {
+    let rucksacks = Line::enumerate(3, lines).map(|line| {
+        if let Some(position) = line.text.find(|c:char| priority(c).is_none()) {
+            return Err(line.error_at(&line.text[position..], "items must be letters"));
+        }
+        if line.text.len() % params.compartments != 0 {
+            return Err(line.error(format!("the {} compartments must be the same size", params.compartments)));
+        }
+        let size = line.text.len() / params.compartments;
+        let compartments = (0..params.compartments).map(|i| line.text[i * size..(i + 1) * size].chars().collect()).collect();
+        Ok(Rucksack {compartments})
+    }).collect::<Result<Vec<Rucksack>>>()?;
+    Ok(Input {rucksacks, group_size: params.group_size})
+}
+
+/// Read the rucksacks, each with two compartments, in groups of three.
+pub fn parse(lines:&[String]) -> Result<Input> {
+    parse_with(lines, &Params::new(false))
+}
+
+/// Each group's badge (the items all its rucksacks have), and the items that are the badge of
+/// more than one group, or of none.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Badges {
+    pub badges: Vec<Items>,
+    pub repeated: Items,
+    pub unused: Items,
+}
+
+impl fmt::Display for Badges {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} groups", self.badges.len())?;
+        for (group, badge) in self.badges.iter().enumerate().filter(|(_, badge)| badge.len() != 1) {
+            writeln!(f, "group {} has {} badges: {}", group + 1, badge.len(), badge)?;
+        }
+        writeln!(f, "badge of more than one group: {}", self.repeated)?;
+        write!(f, "badge of no group: {}", self.unused)
+    }
+}
+
+pub fn badges(input:&Input) -> Result<Badges> {
+    let badges = input.groups()?.map(|group| group.iter().fold(Items::ALL, |common, rucksack| common.intersection(rucksack.items())))
+                      .collect::<Vec<Items>>();
+    let (mut seen, mut repeated) = (Items::EMPTY, Items::EMPTY);
+    for badge in &badges {
+        repeated = repeated.union(seen.intersection(*badge));
+        seen = seen.union(*badge);
+    }
+    Ok(Badges {badges, repeated, unused: Items::ALL.difference(seen)})
+}
+
+/// The priorities of the items in every compartment of each rucksack.
+pub fn part1(input:&Input) -> u32 {
+    input.rucksacks.iter().map(|rucksack| rucksack.common().priority_sum()).sum()
+}
+
+/// The priorities of each group's badge.
+pub fn part2(input:&Input) -> Result<u32> {
+    let badges = badges(input)?;
+    progress!("{}", badges);
+    Ok(badges.badges.iter().map(Items::priority_sum).sum())
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 3, "day3", "Rucksack Reorganization", [AnswerType::Integer, AnswerType::Integer])
+                  .with_params(Params::NAMES),
+        parse: |lines, sample, overrides| parse_with(lines, &Params::new(sample).with(overrides)?),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input)?.into()),
+    });
+}
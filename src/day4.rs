@@ -0,0 +1,35 @@
+use crate::error::{Line, Result};
+use crate::interval::Interval;
+use crate::registry::Registry;
+use crate::scan;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Section assignment pairs, N1-N2,N3-N4 -> (N1..=N2, N3..=N4).
+pub type Input = Vec<(Interval, Interval)>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    Line::enumerate(4, lines).map(|line| {
+        let (a, b, c, d) = scan!(line, "{}-{},{}-{}", i64, i64, i64, i64)?;
+        let interval = |start, end| Interval::new(start, end).ok_or_else(|| line.error(format!("backwards sections {}-{}", start, end)));
+        Ok((interval(a, b)?, interval(c, d)?))
+    }).collect()
+}
+
+/// Number of pairs where one assignment contains the other.
+pub fn part1(pairs:&Input) -> u32 {
+    pairs.iter().filter(|(a, b)| a.contains_interval(*b) || b.contains_interval(*a)).count().try_into().unwrap()
+}
+
+/// Number of pairs whose assignments overlap.
+pub fn part2(pairs:&Input) -> u32 {
+    pairs.iter().filter(|(a, b)| a.overlaps(*b)).count().try_into().unwrap()
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 4, "day4", "Camp Cleanup", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input).into()),
+    });
+}
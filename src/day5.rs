@@ -0,0 +1,95 @@
+use std::collections::VecDeque;
+
+use crate::error::{end_of_input, Result};
+use crate::parse::sections;
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+pub struct Input {
+    /// Crates in each stack, bottom first.
+    pub stacks: Vec<VecDeque<char>>,
+    /// 'move N from A to B' -> [N, A, B], there are always enough crates to move.
+    pub moves: Vec<[usize; 3]>,
+}
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    // The initial layout, then the moves.
+    let [layout, moves_block] = sections(5, lines, ["the stack layout", "the moves"])?;
+    let mut boxes = layout.lines.iter().rev();
+    let mut stacks = Vec::<VecDeque::<char>>::new();
+    let index_line = boxes.next().ok_or_else(|| end_of_input(5, lines, "the stack numbers"))?;
+
+    // Get the indicies of the stack numbers
+    let indicies = index_line.text.chars().enumerate()
+                            .filter_map(|(index, x)| if x != ' ' {Some(index)} else {None});
+    for index in indicies {
+        // Treat short lines as having trailing spaces.
+        stacks.push(boxes.clone().filter_map(|b| {let x = b.text.chars().nth(index).unwrap_or(' ');
+                                                      if x != ' ' {Some(x)} else {None}}).collect());
+    }
+
+    let mut moves = Vec::new();
+    // The height of each stack after the moves so far, so that every move has enough crates.
+    let mut heights = stacks.iter().map(|stack| stack.len()).collect::<Vec<usize>>();
+    for line in &moves_block.lines {
+        let [count, from, to] = line.scan("move {} from {} to {}")?;
+        let mut move_numbers = [line.parse::<usize>(count)?, 0, 0];
+        for (i, stack) in [(1, from), (2, to)] {
+            move_numbers[i] = line.parse::<usize>(stack)?;
+            if !(1..=stacks.len()).contains(&move_numbers[i]) {
+                return Err(line.error_at(stack, "no such stack"));
+            }
+        }
+        let [n, from_index, to_index] = move_numbers;
+        if n > heights[from_index - 1] {
+            let height = heights[from_index - 1];
+            return Err(line.error_at(count, format!("stack {} only has {} crate{}", from_index, height, if height == 1 {""} else {"s"})));
+        }
+        heights[from_index - 1] -= n;
+        heights[to_index - 1] += n;
+        moves.push(move_numbers);
+    }
+
+    Ok(Input {stacks, moves})
+}
+
+// The crate on top of each stack, or a space if it's empty.
+fn top_crates(stacks:&[VecDeque<char>]) -> String {
+    stacks.iter().fold("".to_string(), |current, t| format!("{}{}",current,t.back().unwrap_or(&' ')))
+}
+
+pub fn part1(input:&Input) -> String {
+    let mut stack_positions = input.stacks.clone();
+    for move_numbers in &input.moves {
+        // Move one boxes at a time
+        for _i in 0..move_numbers[0] {
+            // 'parse' checked there are enough crates.
+            if let Some(removed) = stack_positions[move_numbers[1] - 1].pop_back() {
+                stack_positions[move_numbers[2] - 1].push_back(removed);
+            }
+        }
+    }
+    top_crates(&stack_positions)
+}
+
+pub fn part2(input:&Input) -> String {
+    let mut stack_positions = input.stacks.clone();
+    for move_numbers in &input.moves {
+        // Move multiple boxes at a time
+        let last_index = stack_positions[move_numbers[1]-1].len();
+        let mut removed = stack_positions[move_numbers[1] - 1]
+                                .drain((last_index-move_numbers[0])..last_index)
+                                .collect::<VecDeque<char>>();
+        stack_positions[move_numbers[2] - 1].append(&mut removed);
+    }
+    top_crates(&stack_positions)
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 5, "day5", "Supply Stacks", [AnswerType::Text, AnswerType::Text]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input).into()),
+    });
+}
@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+
+use crate::error::{end_of_input, Result};
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// The datastream buffer.
+pub type Input = String;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    // There's only one line
+    lines.first().cloned().ok_or_else(|| end_of_input(6, lines, "the datastream"))
+}
+
+// Position after the first run of 'distinct' unique characters.
+fn find_marker(line:&str, distinct:usize) -> u32 {
+    for i in 0..=(line.len() - distinct) {
+        // Check if range of characters are a unique set.
+        if line[i..i+distinct].chars()
+                .fold(HashSet::new(), |mut h, c| {h.insert(c);h}).len() == distinct {
+            return (i + distinct) as u32;
+        }
+    }
+    0
+}
+
+pub fn part1(line:&Input) -> u32 {
+    find_marker(line, 4)
+}
+
+pub fn part2(line:&Input) -> u32 {
+    find_marker(line, 14)
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 6, "day6", "Tuning Trouble", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input).into()),
+    });
+}
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+use crate::error::{Error, Line, Result};
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Hash of 'full path', total size (including sub directories).
+pub type Input = HashMap<String, u64>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    let mut current_path = VecDeque::<String>::new();
+    let mut dir_hash = HashMap::new();
+    for line in Line::enumerate(7, lines) {
+        let (cd_token, ls_token, dir_token) = ("$ cd ", "$ ls", "dir ");
+        match line.text {
+            text if text.contains(cd_token) => {
+                let dir = line.strip_prefix(cd_token)?;
+                match dir { // Push/pop/reset current path
+                    ".." => {current_path.pop_back();},
+                    "/" =>  {current_path = VecDeque::from(["".to_string()]);},
+                    _ =>  { current_path.push_back(dir.to_string());},
+                }
+            }
+            text if text.contains(ls_token) => {},  // Do nothing for 'ls'
+            text if text.contains(dir_token) => {}, // Ignore 'directories'
+            // The default output is always the file listing
+            _ => {let [size, _file] = line.fields()?;
+                  let size = line.parse::<u64>(size)?;
+
+                  // Accumulate the size for current path and all parents.
+                  current_path.clone().into_iter().fold("".to_string(), |parent, k| {
+                      let current_size = dir_hash.entry(format!("{}/{}",parent,k)).or_insert(0);
+                      *current_size += size;
+                      parent + "/" + &k
+                  });
+            },
+        }
+    }
+    Ok(dir_hash)
+}
+
+pub fn part1(dir_hash:&Input) -> u64 {
+    let mut total = 0;
+    for data in dir_hash.values() {
+        // Only total directories over 100000
+        if *data <= 100000 {
+            total += data;
+        }
+    }
+    total
+}
+
+/// Size of the smallest directory to delete to leave enough unused space.
+pub fn part2(dir_hash:&Input) -> Result<u64> {
+    let max_space:u64 = 70000000;
+    let required_unused_space:u64 = 30000000;
+    let error = |message:String| Error::Solve {day: 7, message};
+    let used = *dir_hash.get("/").ok_or_else(|| error("there are no files in /".to_string()))?;
+    let current_unused = max_space.checked_sub(used).ok_or_else(|| error(format!("{} used is more than the disk's {}", used, max_space)))?;
+    let need_to_free = required_unused_space.saturating_sub(current_unused);
+    Ok(dir_hash.values().filter(|d| **d > need_to_free).fold(max_space, |min,d| std::cmp::min(min,*d)))
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 7, "day7", "No Space Left On Device", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input)?.into()),
+    });
+}
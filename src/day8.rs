@@ -0,0 +1,52 @@
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Tree heights.
+pub type Input = Grid<u32>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    Grid::parse(8, lines, "a digit", |c| c.to_digit(10))
+}
+
+// Trees in each direction from (x, y), nearest first: up, down, left, right.
+fn lines_of_sight(trees:&Input, (x, y):(usize, usize)) -> [Vec<u32>; 4] {
+    let row = trees.row(y);
+    let column = trees.column(x).copied().collect::<Vec<u32>>();
+    [column[..y].iter().rev().copied().collect(), column[y+1..].to_vec(),
+     row[..x].iter().rev().copied().collect(), row[x+1..].to_vec()]
+}
+
+pub fn part1(trees:&Input) -> u32 {
+    let mut count = 0;
+    for position in trees.positions() {
+        let height = trees[position];
+        if lines_of_sight(trees, position).iter().any(|trees| trees.iter().all(|h| *h < height)) {
+            count += 1;
+        }
+    }
+    count
+}
+
+pub fn part2(trees:&Input) -> u32 {
+    let mut count = 0;
+    for position in trees.positions() {
+        let height = trees[position];
+        // Trees that can be seen in each direction, up to and including the first that's as tall.
+        let viewing_distance = |trees:&Vec<u32>| {
+            trees.iter().position(|h| *h >= height).map_or(trees.len(), |blocked| blocked + 1) as u32
+        };
+        count = std::cmp::max(count, lines_of_sight(trees, position).iter().map(viewing_distance).product());
+    }
+    count
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 8, "day8", "Treetop Tree House", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input).into()),
+    });
+}
@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use crate::error::{Line, Result};
+use crate::point::{Direction, Point2};
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// Head movements, (direction, distance).
+pub type Input = Vec<(Direction, u32)>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    let mut moves = Vec::new();
+    for line in Line::enumerate(9, lines) {
+        let [direction, distance] = line.fields()?;
+        let distance = line.parse::<u32>(distance)?;
+        let movement = match direction {
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            _ => {return Err(line.error_at(direction, "unknown move"));}
+        };
+        moves.push((movement, distance));
+    }
+    Ok(moves)
+}
+
+// Number of positions visited by the tail of a rope.
+fn tail_positions(moves:&Input, rope_length:usize) -> u32 {
+    let mut rope_positions = vec![Point2::<i32>::ORIGIN; rope_length];
+    let mut tail_hash_set = HashSet::new();
+
+    // Each knot follows the one in front if they're no longer touching.
+    let tail_func = |head:Point2<i32>, tail:Point2<i32>| {
+        if head.chebyshev(tail) > 1 {tail + (head - tail).signum()} else {tail}
+    };
+
+    tail_hash_set.insert(rope_positions[rope_positions.len()-1]);
+    for (movement, distance) in moves {
+        for _ in 0..*distance {
+            rope_positions[0] += movement.offset();
+
+            for i in 0..(rope_positions.len()-1)  {
+                rope_positions[i+1] = tail_func(rope_positions[i],
+                                              rope_positions[i+1]);
+            }
+            tail_hash_set.insert(rope_positions[rope_positions.len()-1]);
+        }
+    }
+
+    tail_hash_set.len() as u32
+}
+
+pub fn part1(moves:&Input) -> u32 {
+    tail_positions(moves, 2)
+}
+
+pub fn part2(moves:&Input) -> u32 {
+    tail_positions(moves, 10)
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new(crate::YEAR, 9, "day9", "Rope Bridge", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input).into()),
+    });
+}
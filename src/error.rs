@@ -0,0 +1,115 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Errors from reading, parsing or solving a puzzle.
+#[derive(Debug)]
+pub enum Error {
+    /// The input file couldn't be read.
+    Io { path: String, source: std::io::Error },
+    /// The puzzle input is malformed.  Line and column numbers start at 1, the column is only
+    /// known when the problem is with a particular part of the line.
+    Parse { day: u8, line: usize, column: Option<usize>, text: String, message: String },
+    /// The input parsed, but the puzzle can't be solved for it, e.g. a valve can't be reached.
+    Solve { day: u8, message: String },
+    /// A puzzle parameter is invalid, or the day doesn't have it.
+    Param { day: u8, name: String, message: String },
+    /// The answers manifest is malformed.
+    Answers { path: String, message: String },
+    /// There's no solver for the year's day (or no solver with the requested name).
+    Unsupported { year: u16, day: u8, solver: Option<String> },
+    /// The solver was stopped before it finished, e.g. because it ran for longer than 'timeout'.
+    Cancelled { timeout: Option<std::time::Duration> },
+    /// A new day couldn't be added, e.g. because one of its files already exists.
+    Scaffold { path: String, message: String },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io {path, source} => write!(f, "can't read {}: {}", path, source),
+            Error::Parse {day, line, column, text, message} => {
+                write!(f, "day {}, line {}", day, line)?;
+                if let Some(column) = column {
+                    write!(f, ", column {}", column)?;
+                }
+                write!(f, ": {}", message)?;
+                if !text.is_empty() {
+                    write!(f, ": '{}'", text)?;
+                }
+                Ok(())
+            },
+            Error::Solve {day, message} => write!(f, "day {}: {}", day, message),
+            Error::Param {day, name, message} => write!(f, "day {}, parameter '{}': {}", day, name, message),
+            Error::Answers {path, message} => write!(f, "invalid answers file {}: {}", path, message),
+            Error::Unsupported {year, day, solver: None} => write!(f, "Unsupported day {} of {}", day, year),
+            Error::Unsupported {year, day, solver: Some(name)} => {
+                write!(f, "Unsupported day {} of {} (no solver named {})", day, year, name)
+            },
+            Error::Cancelled {timeout: Some(timeout)} => write!(f, "timed out after {:?}", timeout),
+            Error::Cancelled {timeout: None} => write!(f, "cancelled"),
+            Error::Scaffold {path, message} => write!(f, "can't add the day to {}: {}", path, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io {source, ..} => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A line of puzzle input, for reporting where parsing went wrong.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// Line number, starting at 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Wrap each of the input lines, numbering them from 1.
+    pub fn enumerate(day:u8, lines:&'a [String]) -> impl DoubleEndedIterator<Item = Line<'a>> + Clone {
+        lines.iter().enumerate().map(move |(i, text)| Line {day, number: i + 1, text})
+    }
+
+    /// An error for the whole line.
+    pub fn error(&self, message:impl Into<String>) -> Error {
+        Error::Parse {day: self.day, line: self.number, column: None, text: self.text.to_string(), message: message.into()}
+    }
+
+    /// An error at 'field', which must be a slice of this line's text.
+    pub fn error_at(&self, field:&str, message:impl Into<String>) -> Error {
+        let offset = (field.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let column = if offset <= self.text.len() {Some(offset + 1)} else {None};
+        Error::Parse {day: self.day, line: self.number, column, text: self.text.to_string(), message: message.into()}
+    }
+
+    /// Parse 'field' (a slice of this line's text), e.g. as a number.
+    pub fn parse<T: FromStr>(&self, field:&str) -> Result<T> {
+        field.parse::<T>().map_err(|_| self.error_at(field, format!("invalid value '{}'", field)))
+    }
+
+    /// Text after 'prefix', which the line must start with.
+    pub fn strip_prefix(&self, prefix:&str) -> Result<&'a str> {
+        self.text.strip_prefix(prefix).ok_or_else(|| self.error(format!("expected '{}'", prefix.trim())))
+    }
+
+    /// The whitespace separated fields of the line, there must be exactly 'N'.
+    pub fn fields<const N: usize>(&self) -> Result<[&'a str; N]> {
+        let fields = self.text.split_whitespace().collect::<Vec<&str>>();
+        fields.try_into().map_err(|f:Vec<&str>| self.error(format!("expected {} fields, found {}", N, f.len())))
+    }
+}
+
+/// The input ended before the expected line.
+pub fn end_of_input(day:u8, lines:&[String], expected:&str) -> Error {
+    Error::Parse {day, line: lines.len() + 1, column: None, text: String::new(),
+                  message: format!("unexpected end of input, expected {}", expected)}
+}
+
@@ -0,0 +1,206 @@
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Line, Result};
+
+/// Offsets to the 4 orthogonal neighbours, (x, y): right, down, left, up.
+pub const NEIGHBOURS4:[(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+/// Offsets to all 8 neighbours, (x, y), row by row from the top left.
+pub const NEIGHBOURS8:[(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// A rectangular 2D grid, indexed by (x, y) with y increasing down the rows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with each cell set by 'f(x, y)'.
+    pub fn from_fn(width:usize, height:usize, mut f:impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| f(x, y)).collect();
+        Grid {width, height, cells}
+    }
+
+    /// A grid from rows of cells, None if they aren't all the same length.
+    pub fn from_rows(rows:Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {width, height, cells: rows.into_iter().flatten().collect()})
+    }
+
+    /// Parse a character map, one row per line, with 'cell' converting each character (None if
+    /// it isn't valid, reported as "expected <expected>").  The rows must be the same length.
+    pub fn parse(day:u8, lines:&[String], expected:&str, mut cell:impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut grid = Grid {width: 0, height: 0, cells: Vec::new()};
+        for line in Line::enumerate(day, lines) {
+            let row = parse_row(line, expected, &mut cell)?;
+            if grid.height == 0 {
+                grid.width = row.len();
+            } else if row.len() != grid.width {
+                return Err(line.error("rows must be the same length"));
+            }
+            grid.push_row(row);
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The position, if (x, y) is inside the grid.
+    pub fn checked(&self, x:isize, y:isize) -> Option<(usize, usize)> {
+        if x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, x:isize, y:isize) -> bool {
+        self.checked(x, y).is_some()
+    }
+
+    /// The position, wrapping around the edges (as if the grid repeated forever).
+    pub fn wrap(&self, x:isize, y:isize) -> (usize, usize) {
+        (x.rem_euclid(self.width as isize) as usize, y.rem_euclid(self.height as isize) as usize)
+    }
+
+    /// The cell at (x, y), None if it's outside the grid.
+    pub fn get(&self, x:isize, y:isize) -> Option<&T> {
+        self.checked(x, y).map(|position| &self[position])
+    }
+
+    pub fn get_mut(&mut self, x:isize, y:isize) -> Option<&mut T> {
+        self.checked(x, y).map(|position| &mut self[position])
+    }
+
+    /// The cell at (x, y), wrapping around the edges.
+    pub fn get_wrapping(&self, x:isize, y:isize) -> &T {
+        &self[self.wrap(x, y)]
+    }
+
+    // Neighbours at the given offsets that are inside the grid.
+    fn neighbours<'a>(&self, (x, y):(usize, usize), offsets:&'a [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (width, height) = (self.width as isize, self.height as isize);
+        offsets.iter()
+               .map(move |(dx, dy)| (x as isize + dx, y as isize + dy))
+               .filter(move |(x, y)| *x >= 0 && *y >= 0 && *x < width && *y < height)
+               .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    /// The orthogonal neighbours of the position that are inside the grid.
+    pub fn neighbours4(&self, position:(usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(position, &NEIGHBOURS4)
+    }
+
+    /// All the neighbours of the position (including diagonals) that are inside the grid.
+    pub fn neighbours8(&self, position:(usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours(position, &NEIGHBOURS8)
+    }
+
+    pub fn row(&self, y:usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The rows, from the top.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// The cells in the column, from the top.
+    pub fn column(&self, x:usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every position and its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position (row by row) where the cell matches.
+    pub fn position(&self, mut predicate:impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f:impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {width: self.width, height: self.height, cells: self.cells.iter().map(f).collect()}
+    }
+
+    /// Add a row to the bottom, it must be the same width as the grid (any width for an empty grid).
+    pub fn push_row(&mut self, row:Vec<T>) {
+        if self.cells.is_empty() {
+            self.width = row.len();
+        }
+        assert_eq!(row.len(), self.width, "row must be the same width as the grid");
+        self.cells.extend(row);
+        self.height += 1;
+    }
+
+    /// The grid as text, a line per row.
+    pub fn render(&self, f:impl Fn(&T) -> char) -> String {
+        self.rows().map(|row| row.iter().map(&f).chain(std::iter::once('\n')).collect::<String>()).collect()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every cell set to 'fill'.
+    pub fn new(width:usize, height:usize, fill:T) -> Self {
+        Grid {width, height, cells: vec![fill; width * height]}
+    }
+
+    /// Parse a character map like 'parse', padding short rows to the longest with 'fill'.
+    pub fn parse_padded(day:u8, lines:&[String], fill:T, expected:&str, mut cell:impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let rows = Line::enumerate(day, lines).map(|line| parse_row(line, expected, &mut cell)).collect::<Result<Vec<Vec<T>>>>()?;
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        Ok(Grid::from_fn(width, rows.len(), |x, y| rows[y].get(x).cloned().unwrap_or_else(|| fill.clone())))
+    }
+
+    /// Change the size, keeping the cells that are still inside and filling any new ones.
+    pub fn resize(&mut self, width:usize, height:usize, fill:T) {
+        *self = Grid::from_fn(width, height, |x, y| self.get(x as isize, y as isize).cloned().unwrap_or_else(|| fill.clone()));
+    }
+
+    /// Swap the rows and columns.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+}
+
+// Convert each character in the line, reporting the column of any that aren't valid.
+fn parse_row<T>(line:Line, expected:&str, cell:&mut impl FnMut(char) -> Option<T>) -> Result<Vec<T>> {
+    line.text.char_indices()
+        .map(|(i, c)| cell(c).ok_or_else(|| line.error_at(&line.text[i..], format!("expected {}", expected))))
+        .collect()
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y):(usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y):(usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
@@ -0,0 +1,122 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+/// Where to read a day's puzzle input from.
+pub enum InputSource {
+    /// The day's input file, e.g. 'inputs/2022/day01.txt' or 'inputs/2022/day01_sample.txt'.
+    /// For 2022, falls back to the original flat layout in the current directory (e.g.
+    /// 'day1_input.txt' or 'day1_sample_input.txt') when the file isn't in 'inputs'.
+    Default,
+    /// An alternate input for the day, e.g. 'inputs/2022/day01_large.txt' for 'large'.
+    Variant(String),
+    /// A file at the given path.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+    /// Puzzle input held in memory.
+    Text(String),
+    /// Any other reader, e.g. a pipe or a network stream.
+    Reader(Box<dyn BufRead>),
+}
+
+impl InputSource {
+    /// Read the file at 'path', or standard input if the path is '-'.
+    pub fn from_path(path:&str) -> Self {
+        if path == "-" {InputSource::Stdin} else {InputSource::File(PathBuf::from(path))}
+    }
+
+    /// Short description of the input, e.g. for reports.
+    pub fn kind(&self, sample:bool) -> &'static str {
+        match self {
+            InputSource::Default => if sample {"sample"} else {"real"},
+            InputSource::Variant(_) => "variant",
+            InputSource::File(_) => "file",
+            InputSource::Stdin => "stdin",
+            InputSource::Text(_) => "text",
+            InputSource::Reader(_) => "reader",
+        }
+    }
+
+    /// Name of the input file (without the directory), used to look up known answers.
+    pub fn file_name(&self, year:u16, day_number:u8, sample:bool) -> Option<String> {
+        match self {
+            InputSource::Default | InputSource::Variant(_) => self.path(year, day_number, sample).and_then(|path| file_name(&path)),
+            InputSource::File(path) => file_name(path),
+            _ => None,
+        }
+    }
+
+    /// Path of the input file, None if the input isn't a file.
+    pub fn path(&self, year:u16, day_number:u8, sample:bool) -> Option<PathBuf> {
+        match self {
+            InputSource::Default => {
+                let path = input_path(year, day_number, if sample {Some("sample")} else {None});
+                let legacy = PathBuf::from(legacy_file(day_number, sample));
+                Some(if year == crate::YEAR && !path.exists() && legacy.exists() {legacy} else {path})
+            },
+            InputSource::Variant(name) => Some(input_path(year, day_number, Some(name))),
+            InputSource::File(path) => Some(path.clone()),
+            _ => None,
+        }
+    }
+
+    /// Open the input for the year's day, to read a line at a time (e.g. for inputs too large to
+    /// hold in memory).  Also returns a name for the input, to describe it in errors.
+    pub fn open(self, year:u16, day_number:u8, sample:bool) -> Result<(Box<dyn BufRead>, String)> {
+        match self {
+            InputSource::Default | InputSource::Variant(_) => open_file(self.path(year, day_number, sample).unwrap()),
+            InputSource::File(path) => open_file(path),
+            InputSource::Stdin => Ok((Box::new(std::io::stdin().lock()), "<stdin>".to_string())),
+            InputSource::Text(text) => Ok((Box::new(std::io::Cursor::new(text.into_bytes())), "<text>".to_string())),
+            InputSource::Reader(reader) => Ok((reader, "<reader>".to_string())),
+        }
+    }
+
+    /// Read all the input lines for the year's day.
+    pub fn read_lines(self, year:u16, day_number:u8, sample:bool) -> Result<Vec<String>> {
+        let (reader, name) = self.open(year, day_number, sample)?;
+        read_lines(reader, &name)
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(text:&str) -> Self {
+        InputSource::Text(text.to_string())
+    }
+}
+
+/// Directory holding the input files, one subdirectory per year.
+pub const INPUTS_DIR:&str = "inputs";
+
+/// The path of a day's input in the 'inputs' directory, e.g. 'inputs/2022/day01.txt' or
+/// 'inputs/2022/day01_sample.txt' for the 'sample' variant.
+pub fn input_path(year:u16, day_number:u8, variant:Option<&str>) -> PathBuf {
+    let name = match variant {
+        Some(variant) => format!("day{:02}_{}.txt", day_number, variant),
+        None => format!("day{:02}.txt", day_number),
+    };
+    Path::new(INPUTS_DIR).join(year.to_string()).join(name)
+}
+
+// The 2022 input file in the original flat layout, e.g. 'day1_input.txt' or 'day1_sample_input.txt'.
+fn legacy_file(day_number:u8, sample:bool) -> String {
+    format!("day{}_{}input.txt", day_number, if sample {"sample_"} else {""})
+}
+
+fn file_name(path:&Path) -> Option<String> {
+    path.file_name().map(|name| name.to_string_lossy().to_string())
+}
+
+fn open_file(path:PathBuf) -> Result<(Box<dyn BufRead>, String)> {
+    let file = File::open(&path).map_err(|source| Error::Io {path: path.display().to_string(), source})?;
+    Ok((Box::new(BufReader::new(file)), path.display().to_string()))
+}
+
+/// Read all the lines from 'reader', 'name' is used to describe it in errors.
+pub fn read_lines(reader:impl BufRead, name:&str) -> Result<Vec<String>> {
+    reader.lines().collect::<std::io::Result<Vec<String>>>()
+          .map_err(|source| Error::Io {path: name.to_string(), source})
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+
+/// An inclusive range of integers, never empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// The interval from 'start' to 'end' (inclusive), None if 'end' is before 'start'.
+    pub fn new(start:i64, end:i64) -> Option<Self> {
+        (start <= end).then_some(Interval {start, end})
+    }
+
+    /// The interval of just 'x'.
+    pub fn point(x:i64) -> Self {
+        Interval {start: x, end: x}
+    }
+
+    /// Number of integers in the interval, saturating at `u64::MAX` for the whole of i64.
+    pub fn size(&self) -> u64 {
+        self.end.abs_diff(self.start).saturating_add(1)
+    }
+
+    pub fn contains(&self, x:i64) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    /// True if every integer of 'other' is in this interval.
+    pub fn contains_interval(&self, other:Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// True if the intervals have an integer in common.
+    pub fn overlaps(&self, other:Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// True if the intervals overlap or one ends just before the other starts.
+    pub fn touches(&self, other:Interval) -> bool {
+        self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1)
+    }
+
+    /// The integers in both intervals, if any.
+    pub fn intersection(&self, other:Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The integers in either interval, if that's a single interval (see 'touches').
+    pub fn union(&self, other:Interval) -> Option<Interval> {
+        self.touches(other).then(|| Interval {start: self.start.min(other.start), end: self.end.max(other.end)})
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+/// A set of integers, held as sorted intervals that are merged as they're added, so its size
+/// depends on the number of gaps rather than the number of integers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    // Sorted, with a gap between each interval and the next.
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Add the interval, merging it with any it touches.
+    pub fn insert(&mut self, interval:Interval) {
+        let first = self.intervals.partition_point(|i| i.end.saturating_add(1) < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end.saturating_add(1));
+        let merged = self.intervals[first..last].iter().fold(interval, |merged, i| merged.union(*i).unwrap());
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// The intervals, in order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set (saturating, see `Interval::size`).
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().fold(0, |total:u64, i| total.saturating_add(i.size()))
+    }
+
+    // The interval that 'x' would be in.
+    fn find(&self, x:i64) -> Option<&Interval> {
+        let index = self.intervals.partition_point(|i| i.end < x);
+        self.intervals.get(index).filter(|i| i.start <= x)
+    }
+
+    pub fn contains(&self, x:i64) -> bool {
+        self.find(x).is_some()
+    }
+
+    /// True if every integer of the interval is in the set.
+    pub fn covers(&self, interval:Interval) -> bool {
+        self.find(interval.start).is_some_and(|i| i.contains_interval(interval))
+    }
+
+    /// True if any integer of the interval is in the set.
+    pub fn overlaps(&self, interval:Interval) -> bool {
+        let index = self.intervals.partition_point(|i| i.end < interval.start);
+        self.intervals.get(index).is_some_and(|i| i.overlaps(interval))
+    }
+
+    /// The integers in either set.
+    pub fn union(&self, other:&IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        other.intervals.iter().for_each(|i| union.insert(*i));
+        union
+    }
+
+    /// The integers in both sets.
+    pub fn intersection(&self, other:&IntervalSet) -> IntervalSet {
+        let (mut a, mut b) = (self.intervals.iter().peekable(), other.intervals.iter().peekable());
+        let mut intervals = Vec::new();
+        while let (Some(&&x), Some(&&y)) = (a.peek(), b.peek()) {
+            intervals.extend(x.intersection(y));
+            // Move past whichever ends first, the other may overlap the next one.
+            if x.end < y.end {a.next();} else {b.next();}
+        }
+        IntervalSet {intervals}
+    }
+
+    /// The parts of 'within' that aren't in the set.
+    pub fn gaps(&self, within:Interval) -> IntervalSet {
+        let mut gaps = Vec::new();
+        let mut start = Some(within.start);
+        for i in self.intervals.iter().filter(|i| i.overlaps(within)) {
+            gaps.extend(start.zip(i.start.checked_sub(1)).and_then(|(start, end)| Interval::new(start, end)));
+            start = i.end.checked_add(1);
+        }
+        gaps.extend(start.and_then(|start| Interval::new(start, within.end)));
+        IntervalSet {intervals: gaps}
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter:T) -> Self {
+        let mut set = IntervalSet::new();
+        iter.into_iter().for_each(|i| set.insert(i));
+        set
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        let intervals = self.intervals.iter().map(Interval::to_string).collect::<Vec<String>>();
+        write!(f, "{{{}}}", intervals.join(", "))
+    }
+}
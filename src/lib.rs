@@ -0,0 +1,855 @@
+//! Advent of Code 2022 solutions.
+//!
+//! Each day is a module with a typed `parse` function and typed `part1`/`part2` entry points,
+//! e.g. `day1::part1(&day1::parse(&lines))`.  The `registry` wraps them all up as `Solver`s.
+
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod compare;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod log;
+pub mod params;
+pub mod parse;
+pub mod point;
+pub mod registry;
+pub mod report;
+pub mod reporter;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solver;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use answer::Answer;
+use cancel::Cancel;
+use error::{Error, Result};
+use input::InputSource;
+use params::Overrides;
+use registry::Registry;
+use solver::Solver;
+
+pub const YEAR:u16 = 2022;
+
+/// All the solvers, including alternate implementations.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    day1::register(&mut registry);
+    day2::register(&mut registry);
+    day3::register(&mut registry);
+    day4::register(&mut registry);
+    day5::register(&mut registry);
+    day6::register(&mut registry);
+    day7::register(&mut registry);
+    day8::register(&mut registry);
+    day9::register(&mut registry);
+    day10::register(&mut registry);
+    day11::register(&mut registry);
+    day12::register(&mut registry);
+    day13::register(&mut registry);
+    day14::register(&mut registry);
+    day15::register(&mut registry);
+    day16::register(&mut registry);
+    day17::register(&mut registry);
+    day18::register(&mut registry);
+    day19::register(&mut registry);
+    day20::register(&mut registry);
+    day21::register(&mut registry);
+    day22::register(&mut registry);
+    day23::register(&mut registry);
+    day24::register(&mut registry);
+    day25::register(&mut registry);
+    registry
+}
+
+/// Read the input lines for the year's day, e.g. 'inputs/2022/day01.txt' or 'inputs/2022/day01_sample.txt'
+/// (see `InputSource::Default`).
+pub fn read_input(year:u16, day_number:u8, sample:bool) -> Result<Vec<String>> {
+    InputSource::Default.read_lines(year, day_number, sample)
+}
+
+/// Find the named solver for the year's day (or the default).
+pub fn find_solver<'a>(registry:&'a Registry, year:u16, day_number:u8, solver:Option<&str>) -> Result<&'a dyn Solver> {
+    let found = match solver {
+        Some(name) => registry.get_named(year, day_number, name),
+        None => registry.get(year, day_number),
+    };
+    found.ok_or_else(|| Error::Unsupported {year, day: day_number, solver: solver.map(|name| name.to_string())})
+}
+
+/// Run a part of the given year's day's puzzle on the input, using the named solver (or the
+/// default) and overriding any of the day's puzzle parameters.  The solver gives up with an
+/// error once 'cancel' is cancelled.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_day(registry:&Registry, year:u16, day_number:u8, solver:Option<&str>, input:InputSource, second_part:bool,
+                 sample:bool, overrides:&Overrides, cancel:&Cancel) -> Result<Answer> {
+    let solver = find_solver(registry, year, day_number, solver)?;
+    let input = solver.read(input, sample, overrides)?;
+    if !second_part {solver.part1(input.as_ref(), cancel)} else {solver.part2(input.as_ref(), cancel)}
+}
+
+/// Run a part of the given 2022 day's puzzle on the day's input file.
+pub fn call_day_func(registry:&Registry, day_number:u8, solver:Option<&str>, second_part:bool, sample:bool) -> Result<Answer> {
+    solve_day(registry, YEAR, day_number, solver, InputSource::Default, second_part, sample, &Overrides::new(), &Cancel::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use answers::{Answers, ANSWERS_FILE};
+
+    // The known answer for the year's day's sample or real input.
+    fn expected(answers:&Answers, year:u16, day:u8, second_part:bool, sample:bool) -> Option<&str> {
+        let input = InputSource::Default.file_name(year, day, sample).unwrap();
+        answers.get(year, day, &input, if second_part {2} else {1})
+    }
+
+    // Run every implementation registered for the 2022 day against the expected results.
+    fn test_helper(day:u8) {
+        test_year_helper(YEAR, day);
+    }
+
+    // Run every implementation registered for the year's day against the expected results.
+    fn test_year_helper(year:u16, day:u8) {
+        let registry = registry();
+        let answers = Answers::load(ANSWERS_FILE).unwrap();
+        let test_order = [(false, true), (false, false), (true,true), (true, false)];
+        for solver in registry.implementations(year, day) {
+            for test_mode in test_order {
+                let answer = solve_day(&registry, year, day, Some(solver.info().name), InputSource::Default, test_mode.0, test_mode.1,
+                                       &Overrides::new(), &Cancel::new());
+                let part = if test_mode.0 {2} else {1};
+                let expected = expected(&answers, year, day, test_mode.0, test_mode.1).unwrap_or_else(|| {
+                    panic!("No answer for part {} of day {} of {} (sample {}), fill it in in {}", part, day, year, test_mode.1, ANSWERS_FILE)
+                });
+                assert_eq!(answer.unwrap(), expected, "{} part {} sample {}", solver.info().name, part, test_mode.1);
+            }
+        }
+        compare_helper(&registry, year, day);
+    }
+
+    // Check the day's implementations agree on the sample and on generated inputs.
+    fn compare_helper(registry:&registry::Registry, year:u16, day:u8) {
+        let comparison = compare::compare_day(registry, year, day, InputSource::Default, true, &Overrides::new(),
+                                              compare::GENERATED_INPUTS, &Cancel::new()).unwrap();
+        assert!(comparison.mismatches.is_empty(), "day {}: {}", day, comparison);
+    }
+
+    #[test]
+    fn test_registered_days() {
+        // Every registered day must have expected results (and so a test below).
+        let answers = Answers::load(ANSWERS_FILE).unwrap();
+        for solver in registry().iter_all() {
+            let (year, day) = (solver.info().year, solver.info().day);
+            for (second_part, sample) in [(false, true), (false, false), (true, true), (true, false)] {
+                assert!(expected(&answers, year, day, second_part, sample).is_some(), "No expected results for {}", solver.info().name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_answers_manifest() {
+        let answers = Answers::parse("[2022.1]\n\"day1_input.txt\" = { part1 = \"7\" }\n", "test.toml").unwrap();
+        assert_eq!(answers.get(2022, 1, "day1_input.txt", 1), Some("7"));
+        assert_eq!(answers.get(2022, 1, "day1_input.txt", 2), None);
+        assert_eq!(answers.get(2022, 1, "other.txt", 1), None);
+
+        // Answers that aren't in the manifest are unknown, not failures.
+        let run = runner::run_solver(registry().get(YEAR, 1).unwrap(), InputSource::Default, false, &[1, 2], &Overrides::new(), &answers, None);
+        let statuses = run.outcome.unwrap().1.iter().map(|p| p.status).collect::<Vec<_>>();
+        assert_eq!(statuses, [runner::Status::Fail, runner::Status::Unknown]);
+
+        assert_eq!(error_message(Answers::parse("[2022.1]\n\"day1_input.txt\" = { part3 = \"7\" }\n", "test.toml")),
+                   "invalid answers file test.toml: unexpected key 'part3' for 2022.1.\"day1_input.txt\", expected part1 or part2");
+    }
+
+    #[test]
+    fn test_unsupported_day() {
+        assert_eq!(call_day_func(&registry(), 26, None, false, false).unwrap_err().to_string(), "Unsupported day 26 of 2022");
+        assert_eq!(call_day_func(&registry(), 2, Some("day2_new"), false, false).unwrap_err().to_string(),
+                   "Unsupported day 2 of 2022 (no solver named day2_new)");
+        assert_eq!(error_message(solve_day(&registry(), 2021, 1, None, "1".into(), false, false, &Overrides::new(), &Cancel::new())),
+                   "Unsupported day 1 of 2021");
+    }
+
+    fn lines(text:&str) -> Vec<String> {
+        text.lines().map(|l| l.to_string()).collect()
+    }
+
+    fn error_message<T>(result:Result<T>) -> String {
+        match result {
+            Ok(_) => panic!("Expected an error"),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(error_message(day1::parse(&lines("100\n\n2x0"))),
+                   "day 1, line 3, column 1: invalid value '2x0': '2x0'");
+
+        assert_eq!(error_message(day5::parse(&lines(" 1   2\n\nmove 1 from 2 to 3"))),
+                   "day 5, line 3, column 18: no such stack: 'move 1 from 2 to 3'");
+
+        // A truncated monkey block.
+        assert_eq!(error_message(day11::parse(&lines("Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19"))),
+                   "day 11, line 4: unexpected end of input, expected 'Test: divisible by'");
+
+        // Format drift is reported where the line stops matching.
+        assert_eq!(error_message(day15::parse(&lines("Sensor at x=2, y=18: closest beacon at x=-2, y=15"))),
+                   "day 15, line 1, column 37: expected 'is': 'Sensor at x=2, y=18: closest beacon at x=-2, y=15'");
+        assert_eq!(error_message(day13::parse(&lines("[1]\n[2]\n[3]\n\n[4]\n[5]"))),
+                   "day 13, line 3: expected a blank line: '[3]'");
+
+        // A map without the move list.
+        assert_eq!(error_message(day22::parse(&lines(".\n.\n.\n.\n.\n.\n"))),
+                   "day 22, line 7: unexpected end of input, expected the move list");
+
+        // Moving more crates than the stack has.
+        assert_eq!(error_message(day5::parse(&lines("[A]\n 1   2\n\nmove 2 from 1 to 2"))),
+                   "day 5, line 4, column 6: stack 1 only has 1 crate: 'move 2 from 1 to 2'");
+        assert_eq!(error_message(day13::parse(&lines("[1,[2]\n[3]"))), "day 13, line 1: unexpected end of packet: '[1,[2]'");
+        assert_eq!(error_message(day25::parse(&lines(&format!("2{}", "0".repeat(27))))),
+                   "day 25, line 1: SNAFU number is too large: '2000000000000000000000000000'");
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        assert_eq!(error_message(day7::part2(&day7::parse(&lines("$ cd /\n$ ls\ndir a")).unwrap())), "day 7: there are no files in /");
+        assert_eq!(error_message(day21::part1(&day21::parse(&lines("root: abcd + efgh\nabcd: efgh * humn\nefgh: abcd - humn\nhumn: 1")).unwrap())),
+                   "day 21: monkey 'abcd' is waiting on itself");
+        assert_eq!(error_message(day22::part2(&day22::parse(&lines("......\n\n1R1")).unwrap())), "day 22: the map doesn't fold into a cube");
+        assert_eq!(error_message(day16::part1(&day16::parse(&lines("Valve AA has flow rate=0; tunnel leads to valve BB\n\
+                                                                    Valve BB has flow rate=5; tunnel leads to valve AA\n\
+                                                                    Valve CC has flow rate=3; tunnel leads to valve CC")).unwrap(),
+                                               &day16::Params::new(false), &Cancel::new())),
+                   "day 16: valve CC can't be reached from valve AA");
+        // Too steep to climb to the destination.
+        let input = day12::parse(&lines("SaE")).unwrap();
+        assert_eq!(error_message(day12::part1(&input)), "day 12: no route from the start to the destination");
+        assert_eq!(error_message(day12::part2(&input)), "day 12: no route from a lowest point to the destination");
+        // A blizzard that never leaves the only way through.
+        assert_eq!(error_message(day24::part1(&day24::parse(&lines("#.#\n#>#\n#.#")).unwrap(), &Cancel::new())),
+                   "day 24: there's no way through the blizzards");
+        // A negative total is fine.
+        assert_eq!(day25::part1(&lines("-\n=")).unwrap(), "-2");
+        assert_eq!(error_message(day25::part1(&vec![format!("2{}", "0".repeat(26)); 4])), "day 25: the sum is too large");
+    }
+
+    #[test]
+    fn test_scan() {
+        let input = lines("move 3 from  1 to 2\nitems: 1, x\n\nlast");
+        let line = error::Line::enumerate(0, &input).next().unwrap();
+        assert_eq!(scan!(line, "move {} from {} to {}", u8, u8, u8).unwrap(), (3, 1, 2));
+        assert_eq!(line.scan("move {} {}").unwrap(), ["3", "from  1 to 2"]);
+        assert_eq!(error_message(line.scan::<1>("move {} to")), "day 0, line 1, column 8: expected 'to': 'move 3 from  1 to 2'");
+        assert_eq!(error_message(line.scan::<2>("move {} from {}.")), "day 0, line 1, column 14: expected '.': 'move 3 from  1 to 2'");
+
+        let line = error::Line::enumerate(0, &input).nth(1).unwrap();
+        let [items] = line.scan("items: {}").unwrap();
+        assert_eq!(error_message(line.list::<u32>(items, ", ")), "day 0, line 2, column 11: invalid value 'x': 'items: 1, x'");
+
+        let blocks = parse::blocks(0, &input);
+        assert_eq!(blocks.iter().map(|block| block.lines.len()).collect::<Vec<_>>(), [2, 1]);
+        assert_eq!(error_message(blocks[0].line(2, "more")), "day 0, line 3: unexpected blank line, expected more");
+        assert_eq!(error_message(blocks[1].line(1, "more")), "day 0, line 5: unexpected end of input, expected more");
+        assert!(parse::sections(0, &input, ["a", "b"]).is_ok());
+        assert_eq!(error_message(parse::sections(0, &input, ["a", "b", "c"])), "day 0, line 5: unexpected end of input, expected c");
+        assert_eq!(error_message(parse::sections(0, &input, ["a"])), "day 0, line 3: unexpected blank line");
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(42u64), Answer::Integer(42));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert!(Answer::from(i64::MIN).matches("-9223372036854775808"));
+        assert!(Answer::from(-7).matches(" -7\n"));
+        assert_eq!(Answer::from("2=-1=0".to_string()), "2=-1=0");
+        assert_ne!(Answer::None, "0");
+        assert_eq!((Answer::from(3).to_json(), Answer::from("a\"b".to_string()).to_json(), Answer::None.to_json()),
+                   ("3".to_string(), r#""a\"b""#.to_string(), "null".to_string()));
+
+        // The real day 10 picture, which can be checked against its letters or the picture itself.
+        let picture = call_day_func(&registry(), 10, None, true, false).unwrap();
+        assert_eq!(picture.answer_type(), solver::AnswerType::Picture);
+        assert_eq!(picture.ocr().as_deref(), Some("EJCFPGLH"));
+        assert!(picture.matches("EJCFPGLH") && !picture.matches("EJCFPGLL"));
+        assert!(picture.to_string().starts_with("\n####...##..##..####.###...##..#....#..#.\n#......"));
+        assert_eq!(call_day_func(&registry(), 10, None, true, true).unwrap().ocr(), None);
+    }
+
+    #[test]
+    fn test_missing_input() {
+        assert!(matches!(read_input(YEAR, 0, false), Err(Error::Io {..})));
+        assert!(matches!(InputSource::from_path("no_such_input.txt").read_lines(YEAR, 1, false), Err(Error::Io {..})));
+    }
+
+    #[test]
+    fn test_day_selection() {
+        use runner::DaySelection;
+        assert_eq!("5".parse::<DaySelection>(), Ok(DaySelection::One(5)));
+        assert_eq!("1..=12".parse::<DaySelection>(), Ok(DaySelection::Range(1..=12)));
+        assert_eq!("1..13".parse::<DaySelection>(), Ok(DaySelection::Range(1..=12)));
+        assert_eq!("all".parse::<DaySelection>(), Ok(DaySelection::Range(1..=25)));
+        assert!("x..3".parse::<DaySelection>().is_err());
+
+        let answers = Answers::load(ANSWERS_FILE).unwrap();
+        let runs = runner::run_days(&registry(), YEAR, &"1..3".parse().unwrap(), &answers, 1, None);
+        assert_eq!(runs.len(), 4);
+        assert!(runs.iter().all(|run| run.passed()));
+        assert!(report::text_table(&runs).ends_with(" summed over 4 runs (0 failed)\n"));
+
+        // Running on several threads gives the runs in the same order.
+        let key = |run:&runner::DayRun| (run.info.day, run.sample, run.outcome.as_ref().unwrap().1.iter().map(|p| p.answer.as_ref().ok().cloned()).collect::<Vec<_>>());
+        let parallel = runner::run_days(&registry(), YEAR, &"1..=6".parse().unwrap(), &answers, 3, None);
+        let serial = runner::run_days(&registry(), YEAR, &"1..=6".parse().unwrap(), &answers, 1, None);
+        assert_eq!(parallel.iter().map(key).collect::<Vec<_>>(), serial.iter().map(key).collect::<Vec<_>>());
+        assert_eq!(runner::run_days(&registry(), YEAR, &"1..=6".parse().unwrap(), &answers, 0, None).len(), 12);
+    }
+
+    #[test]
+    fn test_report_formats() {
+        let registry = registry();
+        let answers = Answers::default();
+        let runs = [runner::run_solver(registry.get(YEAR, 10).unwrap(), InputSource::Default, true, &[2], &Overrides::new(), &answers, None),
+                    runner::run_solver(registry.get(YEAR, 1).unwrap(), InputSource::from_path("no_such_input.txt"), false, &[1], &Overrides::new(), &answers, None)];
+
+        let csv = report::csv(&runs);
+        let mut rows = csv.split(",sample,");
+        assert!(rows.next().unwrap().starts_with("year,day,part,solver,input,answer,answer_type,parse_ns,duration_ns,status,error\n"));
+        assert!(rows.next().unwrap().starts_with("\"\n##..##"));
+        assert!(csv.contains("\n2022,1,1,day1,file,,integer,0,0,error,can't read no_such_input.txt"));
+
+        let json = report::json(&runs);
+        assert!(json.contains(r#""answer": "\n##..##..##..##..##..##..##..##..##..##..\n###...###"#));
+        assert!(json.contains(r#""part": 2, "solver": "day10", "input": "sample""#));
+        assert!(json.contains(r#""status": "error", "error": "can't read no_such_input.txt"#));
+    }
+
+    #[test]
+    fn test_params() {
+        let registry = registry();
+        let solve = |day, overrides:&Overrides| solve_day(&registry, YEAR, day, None, InputSource::Default, false, true, overrides, &Cancel::new());
+        // The puzzle's example of 20 rounds without any relief.
+        assert_eq!(solve(11, &Overrides::new().set("relief", 1)).unwrap(), "10197");
+        assert_eq!(solve(15, &Overrides::new().set("row", 11)).unwrap(), "28");
+        // Far from the rocks, the sand piles up in a triangle to the floor (11 wide at the bottom),
+        // spreading left of x=0.
+        let source_0 = Overrides::new().set("source_x", 0);
+        assert_eq!(solve_day(&registry, YEAR, 14, None, InputSource::Default, true, true, &source_0, &Cancel::new()).unwrap(), "121");
+        assert_eq!(day19::Params::new(false).with(&Overrides::new().set("part2_blueprints", 2)).unwrap(),
+                   day19::Params {minutes: 24, part2_minutes: 32, part2_blueprints: 2});
+
+        assert_eq!(error_message(solve(15, &Overrides::new().set("row", "x"))), "day 15, parameter 'row': invalid value 'x'");
+        assert_eq!(error_message(solve(15, &Overrides::new().set("rows", 1))),
+                   "day 15, parameter 'rows': no such parameter, expected one of row, search_area");
+        assert_eq!(error_message(solve(2, &Overrides::new().set("rows", 1))), "day 2, parameter 'rows': the day has no parameters");
+        assert_eq!(error_message(solve(16, &Overrides::new().set("start", "ZZ"))), "day 16, parameter 'start': no such valve ZZ");
+        assert_eq!(error_message(solve(11, &Overrides::new().set("relief", 0))), "day 11, parameter 'relief': must be at least 1");
+
+        // Answers are only known for the default parameters.
+        let answers = Answers::load(ANSWERS_FILE).unwrap();
+        let run = runner::run_solver(registry.get(YEAR, 11).unwrap(), InputSource::Default, true, &[1],
+                                     &Overrides::new().set("rounds", 20), &answers, None);
+        assert_eq!(run.outcome.unwrap().1[0].status, runner::Status::Unknown);
+        assert_eq!("row=10".parse::<params::Param>(), Ok(params::Param {name: "row".to_string(), value: "10".to_string()}));
+        assert!("10".parse::<params::Param>().is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        let options = bench::Options {warm_up: std::time::Duration::ZERO, measure: std::time::Duration::ZERO, max_samples: 1};
+        let measurements = bench::bench_solver(registry().get(YEAR, 1).unwrap(), &read_input(YEAR, 1, true).unwrap(), true, &options).unwrap();
+        assert_eq!(measurements.iter().map(|m| m.id.as_str()).collect::<Vec<&str>>(), ["2022/day1/parse", "2022/day1/part1", "2022/day1/part2"]);
+        assert!(measurements.iter().all(|m| m.samples.len() == 1));
+        // Each sample is one run, even without any time to warm up or measure in.
+        let mut runs = 0;
+        let measurement = bench::measure("test".to_string(), &options, || {runs += 1; std::thread::sleep(std::time::Duration::from_millis(2))});
+        assert_eq!((runs, measurement.samples.len()), (1, 1));
+        assert!(measurement.min() >= std::time::Duration::from_millis(2));
+        let options = bench::Options {warm_up: std::time::Duration::from_millis(20), ..options};
+        let measurement = bench::measure("test".to_string(), &options, || std::thread::sleep(std::time::Duration::from_millis(2)));
+        assert!(measurement.max() < std::time::Duration::from_millis(15));
+
+        let path = std::env::temp_dir().join(format!("advent_bench_{}.csv", std::process::id()));
+        bench::save_baseline(&path, &measurements).unwrap();
+        let baseline = bench::load_baseline(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(baseline.get("2022/day1/part2"), Some(&measurements[2].median()));
+        assert!(bench::table(&measurements, &baseline).lines().nth(1).unwrap().starts_with("2022/day1/parse"));
+        let filters = |filters:&[&str]| filters.iter().map(|f| f.to_string()).collect::<Vec<String>>();
+        assert!(bench::selected("2022/day1/part2", &filters(&[])));
+        assert!(bench::selected("2022/day1/part2", &filters(&["day10", "day1"])));
+        assert!(bench::selected("2022/day1/part2", &filters(&["day1/part2"])));
+        assert!(!bench::selected("2022/day10/part2", &filters(&["day1"])));
+        assert!(!bench::selected("2022/day1/part2", &filters(&["day1/part1", "part"])));
+    }
+
+    #[test]
+    fn test_log_levels() {
+        assert_eq!(log::level_for_verbosity(0), log::Level::Quiet);
+        assert_eq!(log::level_for_verbosity(1), log::Level::Progress);
+        assert_eq!(log::level_for_verbosity(3), log::Level::Trace);
+        // Quiet by default, the tests share the level so leave it alone.
+        assert!(!log::enabled(log::Level::Progress));
+    }
+
+    #[test]
+    fn test_grid() {
+        use grid::Grid;
+        let grid = Grid::parse(0, &lines("abc\ndef"), "a letter", |c| c.is_ascii_lowercase().then_some(c)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get_wrapping(-1, 2), &'c');
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+        assert_eq!(grid.position(|c| *c == 'e'), Some((1, 1)));
+        assert_eq!(grid.transpose().render(|c| *c), "ad\nbe\ncf\n");
+
+        let mut padded = Grid::parse_padded(0, &lines("ab\nc"), ' ', "a letter", Some).unwrap();
+        assert_eq!(padded.render(|c| *c), "ab\nc \n");
+        padded.resize(3, 1, '.');
+        assert_eq!(padded.render(|c| *c), "ab.\n");
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+
+        assert_eq!(error_message(Grid::parse(0, &lines("ab\na"), "a letter", Some)), "day 0, line 2: rows must be the same length: 'a'");
+        assert_eq!(error_message(Grid::parse(0, &lines("a1"), "a letter", |c| c.is_ascii_lowercase().then_some(c))),
+                   "day 0, line 1, column 2: expected a letter: 'a1'");
+    }
+
+    #[test]
+    fn test_point() {
+        use point::{Direction, Point2, Point3};
+        let (a, b) = (Point2::new(1, 2), Point2::new(-3, 5));
+        assert_eq!(a + b, Point2::new(-2, 7));
+        assert_eq!(a - b, Point2::new(4, -3));
+        assert_eq!(-a * 2, Point2::new(-2, -4));
+        assert_eq!((a.dot(b), a.cross(b)), (7, 11));
+        assert_eq!((a.manhattan(b), a.chebyshev(b)), (7, 4));
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        assert_eq!(Direction::Right.offset::<i32>().rotate_left(), Direction::Up.offset());
+        assert_eq!(Direction::Right.offset::<i32>().rotate_right(), Direction::Down.offset());
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left().reverse(), Direction::Right);
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+
+        let (x, y, z) = (Point3::new(1, 0, 0), Point3::new(0, 1, 0), Point3::new(0, 0, 1));
+        assert_eq!(x.cross(y), z);
+        assert_eq!(x.rotate_left(z), y);
+        assert_eq!(x.rotate_right(z), -y);
+        assert_eq!(Point3::new(1, -2, 3).manhattan(Point3::ORIGIN), 6);
+        assert_eq!(Point3::new(1, -2, 3).chebyshev(Point3::ORIGIN), 3);
+        assert_eq!(Point3::<i32>::AXES.iter().fold(Point3::ORIGIN, |sum, axis| sum + *axis), Point3::ORIGIN);
+    }
+
+    #[test]
+    fn test_search() {
+        // a -1-> b -1-> c, and a -5-> c directly, d is unreachable.
+        let edges = |node:&char| match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 1)],
+            _ => vec![],
+        };
+        let bfs = search::bfs(&edges, ['a'], |_| false);
+        assert_eq!((bfs.cost(&'c'), bfs.cost(&'d')), (Some(1), None));
+        let dijkstra = search::dijkstra(&edges, ['a'], |node| *node == 'c');
+        assert_eq!((dijkstra.goal(), dijkstra.goal_cost()), (Some(&'c'), Some(2)));
+        assert_eq!(dijkstra.goal_path(), Some(vec!['a', 'b', 'c']));
+        let astar = search::astar(&edges, ['a'], |node| *node == 'c', |node| ('c' as u64).abs_diff(*node as u64));
+        assert_eq!(astar.goal_cost(), Some(2));
+        let pairs = search::all_pairs(&edges, &['a', 'b', 'c', 'd']);
+        assert_eq!((pairs[&('a', 'c')], pairs[&('c', 'c')], pairs.get(&('c', 'a'))), (2, 0, None));
+
+        // Several starts, on an unbounded grid.
+        let steps = |p:&point::Point2<i32>| point::Direction::ALL.map(|d| (*p + d.offset(), 1));
+        let grid = search::bfs(&steps, [point::Point2::new(0, 0), point::Point2::new(10, 0)], |p| p.x == 7);
+        assert_eq!(grid.goal_path().map(|path| path.len()), Some(4));
+    }
+
+    #[test]
+    fn test_input_sources() {
+        let registry = registry();
+        let text = "1000\n2000\n\n4000\n\n500\n";
+        assert_eq!(solve_day(&registry, YEAR, 1, None, text.into(), false, false, &Overrides::new(), &Cancel::new()).unwrap(), "4000");
+        let reader = Box::new(std::io::Cursor::new(text.as_bytes().to_vec()));
+        assert_eq!(solve_day(&registry, YEAR, 1, None, InputSource::Reader(reader), true, false, &Overrides::new(), &Cancel::new()).unwrap(), "7500");
+        assert_eq!(solve_day(&registry, YEAR, 1, None, InputSource::from_path("day1_sample_input.txt"), false, true, &Overrides::new(), &Cancel::new()).unwrap(), "24000");
+
+        // The flat 2022 files are used when there's nothing in 'inputs'.
+        assert_eq!(input::input_path(2023, 7, None), std::path::Path::new("inputs/2023/day07.txt"));
+        assert_eq!(input::input_path(2023, 12, Some("sample")), std::path::Path::new("inputs/2023/day12_sample.txt"));
+        assert_eq!(InputSource::Default.file_name(YEAR, 3, true).as_deref(), Some("day3_sample_input.txt"));
+        assert_eq!(InputSource::Default.file_name(2023, 3, true).as_deref(), Some("day03_sample.txt"));
+        assert_eq!(InputSource::Variant("large".to_string()).file_name(YEAR, 3, false).as_deref(), Some("day03_large.txt"));
+        assert!(matches!(InputSource::Variant("large".to_string()).read_lines(YEAR, 3, false), Err(Error::Io {..})));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let registry = registry();
+        let cancel = Cancel::new();
+        assert!(!cancel.is_cancelled() && cancel.check().is_ok());
+        cancel.clone().cancel();
+        for day in [16, 19, 24] {
+            assert_eq!(error_message(solve_day(&registry, YEAR, day, None, InputSource::Default, true, false, &Overrides::new(), &cancel)),
+                       "cancelled");
+        }
+        let timeout = Cancel::with_timeout(Some(std::time::Duration::ZERO));
+        assert_eq!(error_message(solve_day(&registry, YEAR, 19, None, InputSource::Default, false, true, &Overrides::new(), &timeout)),
+                   "timed out after 0ns");
+        // Cancelling a token with a time limit isn't a timeout, and a limit too long to represent is no limit.
+        let limited = Cancel::with_timeout(Some(std::time::Duration::from_secs(3600)));
+        limited.cancel();
+        assert_eq!(error_message(limited.check()), "cancelled");
+        assert!(Cancel::with_timeout(Some(std::time::Duration::MAX)).check().is_ok());
+
+        // Days that finish quickly don't check.
+        assert_eq!(solve_day(&registry, YEAR, 1, None, InputSource::Default, false, true, &Overrides::new(), &cancel).unwrap(), "24000");
+        let run = runner::run_solver(registry.get(YEAR, 24).unwrap(), InputSource::Default, true, &[1, 2], &Overrides::new(),
+                                     &Answers::default(), Some(std::time::Duration::ZERO));
+        assert!(!run.passed());
+        let (_, parts) = run.outcome.unwrap();
+        assert_eq!(parts.iter().map(|p| (p.part, p.status)).collect::<Vec<_>>(), [(1, runner::Status::Error), (2, runner::Status::Error)]);
+        assert_eq!(error_message(parts.into_iter().next().unwrap().answer), "timed out after 0ns");
+
+        // A part that times out doesn't lose the other part's answer.
+        let solver = solver::DaySolver {
+            info: solver::SolverInfo::new(YEAR, 1, "day1_slow", "Calorie Counting", [solver::AnswerType::Integer, solver::AnswerType::Integer]),
+            parse: |lines, _, _| day1::parse(lines),
+            part1: |input, _| Ok(day1::part1(input).into()),
+            part2: |_, cancel| {cancel.check()?; Ok(Answer::None)},
+        };
+        let run = runner::run_solver(&solver, InputSource::Default, true, &[1, 2], &Overrides::new(),
+                                     &Answers::load(ANSWERS_FILE).unwrap(), Some(std::time::Duration::ZERO));
+        let text = report::text_table(std::slice::from_ref(&run));
+        assert!(text.contains("24000   ok") && text.contains("error: timed out after 0ns  ERROR"), "{}", text);
+        assert!(report::csv(std::slice::from_ref(&run)).contains(",2,day1_slow,sample,,integer,"));
+    }
+
+    #[test]
+    fn test_reporter() {
+        use std::sync::{Arc, Mutex};
+
+        // Only keeps this test's updates, as the reporter is shared with any other tests running.
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<String>>);
+        impl reporter::Reporter for Recorder {
+            fn update(&self, task:&str, phase:&str, done:u64, total:Option<u64>) {
+                if task.starts_with("test") {
+                    self.0.lock().unwrap().push(format!("{}: {} {}/{:?}", task, phase, done, total));
+                }
+            }
+            fn finish(&self, task:&str) {
+                if task.starts_with("test") {
+                    self.0.lock().unwrap().push(format!("{}: done", task));
+                }
+            }
+        }
+
+        let recorder = Arc::new(Recorder::default());
+        reporter::set_reporter(Some(recorder.clone()));
+        reporter::update("ignored", 1, None);
+        let answer = reporter::task("test day 19", || {
+            solve_day(&registry(), YEAR, 19, None, InputSource::Default, false, true, &Overrides::new(), &Cancel::new())
+        });
+        reporter::set_reporter(None);
+        assert_eq!(answer.unwrap(), "33");
+        assert_eq!(*recorder.0.lock().unwrap(), ["test day 19: blueprint 0/Some(2)", "test day 19: blueprint 1/Some(2)", "test day 19: done"]);
+    }
+
+    #[test]
+    fn test_calories() {
+        // Elves 2, 4 and 5 tie for second place.
+        let text = "9\n\n4\n3\n\n1\n\n7\n\n5\n2\n\n3";
+        let summary = day1::read(std::io::Cursor::new(text), "test", 2, true).unwrap();
+        assert_eq!(summary.top, [day1::Elf {index: 1, calories: 9}, day1::Elf {index: 2, calories: 7}]);
+        assert_eq!((summary.elves, summary.calories, summary.ties), (6, 34, 2));
+        assert_eq!((summary.mean().map(|mean| (mean * 3.0).round()), summary.median()), (Some(17.0), Some(7.0)));
+        assert_eq!(summary.to_string().lines().last(), Some("Mean 5.7, median 7.0"));
+        assert!(summary.to_string().contains("\n   2. elf 2: 7\n2 more elves carry 7 calories\n"));
+
+        // Larger elves replace the tied ones.
+        let summary = day1::parse_top(&lines("7\n\n7\n\n7\n\n8\n\n1"), 2).unwrap();
+        assert_eq!((summary.top_calories(), summary.ties, summary.median()), (15, 2, None));
+        assert_eq!(day1::parse_top(&lines("7\n\n7\n\n8\n\n9"), 2).unwrap().ties, 0);
+
+        let solve = |overrides:&Overrides| solve_day(&registry(), YEAR, 1, None, InputSource::Default, true, true, overrides, &Cancel::new());
+        assert_eq!(solve(&Overrides::new().set("top", 5)).unwrap(), "55000");
+        assert_eq!(solve(&Overrides::new().set("top", 2)).unwrap(), "35000");
+        assert_eq!(solve(&Overrides::new().set("top", 0)).unwrap(), "0");
+        assert_eq!(error_message(solve(&Overrides::new().set("top", 6))), "day 1: there are only 5 elves, not 6");
+
+        // The solver reads the input as it goes, and parsing lines gives the same summary.  A
+        // blank last line doesn't start another elf, but an empty elf before it is still one.
+        let registry = registry();
+        let solver = registry.get(YEAR, 1).unwrap();
+        for text in ["1\n\n2\n", "1\n\n2\n\n"] {
+            let read = solver.read(InputSource::from(text), false, &Overrides::new()).unwrap();
+            let parsed = solver.parse(&lines(text), false, &Overrides::new()).unwrap();
+            for input in [read, parsed] {
+                let (summary, _) = input.downcast_ref::<(day1::Summary, day1::Params)>().unwrap();
+                assert_eq!((summary.elves, summary.calories, summary.ties), (2, 3, 0));
+            }
+        }
+        let summary = day1::parse_top(&lines("1\n\n2\n\n\n"), 3).unwrap();
+        assert_eq!((summary.elves, summary.top_calories()), (3, 3));
+        assert_eq!(error_message(day1::parse(&lines(&format!("{}\n\n1", u64::MAX)))), "day 1, line 3: too many calories in total: '1'");
+    }
+
+    #[test]
+    fn test_rucksacks() {
+        use day3::{Items, Params};
+        let items = "vJrwpWtwJgWr".chars().collect::<Items>();
+        assert_eq!(items.to_string(), "gprtvwJW");
+        assert_eq!(items.intersection("hcsFMMfFFhFp".chars().collect()).to_string(), "p");
+        assert_eq!(items.union("aZ".chars().collect()).len(), 10);
+        assert!(items.contains('J') && !items.contains('j') && !items.contains('1'));
+        assert_eq!(Items::ALL.len(), 52);
+        assert_eq!(Items::ALL.priority_sum(), 52 * 53 / 2);
+
+        // A trailing partial group is an error for part 2, not a panic, and part 1 doesn't need it.
+        let sample = lines(&std::fs::read_to_string("day3_sample_input.txt").unwrap());
+        let partial = day3::parse(&sample[..5]).unwrap();
+        assert_eq!(day3::part1(&partial), 16 + 38 + 42 + 22 + 20);
+        assert_eq!(error_message(day3::part2(&partial)), "day 3, line 6: unexpected end of input, expected 1 more rucksack for the last group of 3");
+        assert_eq!(error_message(day3::parse(&lines("abC1"))), "day 3, line 1, column 4: items must be letters: 'abC1'");
+
+        // Pairs of elves with a single compartment each.
+        let input = day3::parse_with(&lines("abcd\naXbY\naQ\nZa\nxy\nyz"), &Params {compartments: 1, group_size: 2}).unwrap();
+        let badges = day3::badges(&input).unwrap();
+        assert_eq!(badges.badges.iter().map(Items::to_string).collect::<Vec<_>>(), ["ab", "a", "y"]);
+        assert_eq!(badges.repeated.to_string(), "a");
+        assert_eq!(badges.unused.len(), 52 - 3);
+        assert_eq!(day3::part2(&input).unwrap(), 1 + 2 + 1 + 25);
+        assert_eq!(error_message(day3::parse_with(&sample, &Params {compartments: 3, group_size: 3})),
+                   "day 3, line 2: the 3 compartments must be the same size: 'jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL'");
+        let registry = registry();
+        let solve = |overrides:&Overrides| solve_day(&registry, YEAR, 3, None, InputSource::Default, true, true, overrides, &Cancel::new());
+        assert_eq!(error_message(solve(&Overrides::new().set("group_size", 0))), "day 3, parameter 'group_size': must be at least 1");
+        assert_eq!(solve(&Overrides::new().set("group_size", 1)).unwrap(), "2278");
+    }
+
+    #[test]
+    fn test_intervals() {
+        use interval::{Interval, IntervalSet};
+        let interval = |start, end| Interval::new(start, end).unwrap();
+        let (a, b) = (interval(2, 8), interval(3, 7));
+        assert!(a.contains_interval(b) && !b.contains_interval(a) && a.overlaps(b));
+        assert_eq!(interval(1, 4).intersection(interval(5, 9)), None);
+        assert_eq!(interval(1, 4).union(interval(5, 9)), Some(interval(1, 9)));
+        assert_eq!(interval(1, 4).union(interval(6, 9)), None);
+        assert_eq!(Interval::new(5, 4), None);
+        assert_eq!(interval(i64::MIN, i64::MAX).size(), u64::MAX);
+        assert_eq!(interval(-3_000_000_000_000, 3_000_000_000_000).size(), 6_000_000_000_001);
+
+        let mut set = [interval(10, 20), interval(30, 40), interval(-5, 0)].into_iter().collect::<IntervalSet>();
+        assert_eq!(set.len(), 11 + 11 + 6);
+        set.insert(interval(21, 29));
+        assert_eq!(set.to_string(), "{-5..=0, 10..=40}");
+        assert!(set.covers(interval(12, 40)) && !set.covers(interval(0, 10)) && set.overlaps(interval(0, 10)));
+        assert!(set.contains(-5) && !set.contains(5) && !set.overlaps(interval(1, 9)));
+        assert_eq!(set.gaps(interval(-10, 50)).to_string(), "{-10..=-6, 1..=9, 41..=50}");
+        assert_eq!(set.gaps(interval(i64::MIN, i64::MAX)).len(), u64::MAX - set.len() + 1);
+
+        let other = [interval(-1, 5), interval(35, i64::MAX)].into_iter().collect::<IntervalSet>();
+        assert_eq!(set.intersection(&other).to_string(), "{-1..=0, 35..=40}");
+        assert_eq!(set.union(&other).to_string(), format!("{{-5..=5, 10..={}}}", i64::MAX));
+        assert_eq!(error_message(day4::parse(&lines("2-4,6-5"))), "day 4, line 1: backwards sections 6-5: '2-4,6-5'");
+    }
+
+    #[test]
+    fn test_games() {
+        use day2::{Game, Outcome};
+        // The arithmetic solver's rules are the cyclic game, and score every round the same.
+        let (game, cyclic) = (Game::rock_paper_scissors(), Game::cyclic(&["rock", "paper", "scissors"]).unwrap());
+        for (me, elf) in (0..3).flat_map(|me| (0..3).map(move |elf| (me, elf))) {
+            assert_eq!(game.outcome(me, elf), cyclic.outcome(me, elf));
+            let round = lines(&format!("{} {}", "ABC".as_bytes()[elf] as char, "XYZ".as_bytes()[me] as char));
+            let (guide, old) = (game.parse(&round).unwrap(), day2::parse_old(&round).unwrap());
+            assert_eq!(game.score_hands(&guide).unwrap(), day2::part1_old(&old));
+            assert_eq!(game.score_outcomes(&guide).unwrap(), day2::part2_old(&old));
+        }
+        assert_eq!(game.outcome(0, 2), Outcome::Win);
+        assert_eq!(error_message(game.parse(&lines("A Y\nB Q"))), "day 2, line 2, column 3: expected one of XYZ: 'B Q'");
+        assert_eq!(error_message(game.parse(&lines("D X"))), "day 2, line 1, column 1: expected one of ABC: 'D X'");
+
+        // Part 1 needs all 5 hands, part 2 still has 3 outcomes and plays the best scoring hand
+        // (lizard to lose to rock, spock to beat scissors).
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let guide = game.parse(&lines("A W\nD Z\nE V")).unwrap();
+        assert_eq!(game.score_hands(&guide).unwrap(), 8 + 11 + 7);
+        assert_eq!(error_message(game.score_outcomes(&guide)), "day 2, line 1: expected an outcome (XYZ)");
+        assert_eq!(game.score_outcomes(&game.parse(&lines("A X\nC Z")).unwrap()).unwrap(), 5 + 10);
+
+        let game = game.with_symbols("RPSKL", "rpskl", "ldw").unwrap().with_scores(&[1, 1, 1, 1, 1], [0, 1, 2]).unwrap();
+        assert_eq!(game.score_hands(&game.parse(&lines("R p\nK k")).unwrap()).unwrap(), 3 + 2);
+        assert_eq!(Game::rock_paper_scissors().with_symbols("ABC", "XYY", "XYZ").unwrap_err(), "the hand symbols 'XYY' aren't distinct");
+        assert_eq!(Game::new(&["a", "b"], |a, b| a < b).unwrap_err(), "no hand beats a");
+        assert_eq!(Game::new(&["a", "b"], |_, _| true).unwrap_err(), "a beats itself");
+        assert_eq!(Game::cyclic(&["a", "b", "c", "d"]).unwrap_err(), "a cyclic game needs an odd number of hands, not 4");
+    }
+
+    #[test]
+    fn test_compare() {
+        use solver::{AnswerType, DaySolver, SolverInfo};
+        // An "optimised" day 2 that scores a scissors draw as a loss.
+        let mut registry = registry::Registry::new();
+        day2::register(&mut registry);
+        registry.register(DaySolver {
+            info: SolverInfo::new(YEAR, 2, "day2_fast", "Rock Paper Scissors", [AnswerType::Integer, AnswerType::Integer]),
+            parse: |lines, _, _| day2::parse(lines),
+            part1: |(game, guide), _| {
+                let draws = guide.rounds.iter().filter(|round| round.elf == 2 && round.hand == Some(2)).count() as u32;
+                Ok((game.score_hands(guide)? - 3 * draws).into())
+            },
+            part2: |input, _| Ok(day2::part2(input)?.into()),
+        });
+        let comparison = compare::compare_day(&registry, YEAR, 2, InputSource::Default, true, &Overrides::new(),
+                                              compare::GENERATED_INPUTS, &Cancel::new()).unwrap();
+        assert_eq!(comparison.solvers, ["day2", "day2_old", "day2_fast"]);
+        assert_eq!(comparison.inputs, compare::GENERATED_INPUTS + 1);
+        // Shrunk to the sample's last round.
+        let [mismatch] = comparison.mismatches.as_slice() else {panic!("{}", comparison)};
+        assert_eq!(mismatch.part, 1);
+        assert_eq!(mismatch.source, "the input");
+        assert_eq!(mismatch.lines, ["C Z"]);
+        assert_eq!(mismatch.answers, [("day2", Ok("6".to_string())), ("day2_old", Ok("6".to_string())), ("day2_fast", Ok("3".to_string()))]);
+        assert!(mismatch.to_string().ends_with("day2_fast = 3\n  minimal failing line: 'C Z'"));
+
+        // Failing implementations only disagree with ones that succeed.
+        let solvers = registry.implementations(YEAR, 2).collect::<Vec<_>>();
+        assert_eq!(compare::compare_lines(&solvers, &lines("A Y\nQ Z"), 1, false, &Overrides::new(), &Cancel::new()).unwrap(), None);
+
+        // A cancelled comparison is an error, not a disagreement or an agreement.
+        let cancel = Cancel::new();
+        cancel.cancel();
+        assert_eq!(error_message(compare::compare_lines(&solvers, &lines("C Z"), 1, false, &Overrides::new(), &cancel)), "cancelled");
+        assert_eq!(error_message(compare::compare_day(&registry, YEAR, 2, InputSource::Default, true, &Overrides::new(), 0, &cancel)),
+                   "cancelled");
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join(format!("advent_scaffold_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        // The crate without day 2 (the test registering it itself stays).
+        let real_lib = std::fs::read_to_string("src/lib.rs").unwrap();
+        let without_day2 = real_lib.replace("pub mod day2;\n", "").replace("\n    day2::register(&mut registry);\n", "\n")
+                                   .replace("    #[test]\n    fn test_day2() { test_helper(2); }\n", "");
+        assert!(without_day2.contains("        day2::register(&mut registry);\n"));
+        std::fs::write(root.join("src/lib.rs"), &without_day2).unwrap();
+        let read = |path:&str| std::fs::read_to_string(root.join(path)).unwrap();
+
+        let written = scaffold::new_day(&root, YEAR, 2, "Rock \"Paper\" Scissors").unwrap();
+        assert_eq!(written.len(), 5);
+        assert_eq!(read("src/lib.rs"), real_lib);
+        assert!(read("src/day2.rs").contains("SolverInfo::new(crate::YEAR, 2, \"day2\", \"Rock \\\"Paper\\\" Scissors\""));
+        assert_eq!(read("inputs/2022/day02_sample.txt"), "");
+        assert!(Answers::load(root.join(ANSWERS_FILE)).unwrap().contains_day(YEAR, 2));
+
+        // Nothing is overwritten.
+        assert_eq!(error_message(scaffold::new_day(&root, YEAR, 2, "")),
+                   format!("can't add the day to {}: it already exists", root.join("src/day2.rs").display()));
+        std::fs::remove_file(root.join("src/day2.rs")).unwrap();
+        assert!(error_message(scaffold::new_day(&root, YEAR, 2, "")).ends_with("it already exists"));
+        assert!(error_message(scaffold::new_day(&root, YEAR, 26, "")).ends_with("there's no day 26"));
+
+        // Other years get a module of their own, registered in 'registry' and tested in 'tests'.
+        scaffold::new_day(&root, 2023, 5, "").unwrap();
+        scaffold::new_day(&root, 2023, 1, "").unwrap();
+        let lib = read("src/lib.rs");
+        assert!(lib.contains("pub mod day25;\npub mod year2023;\n"));
+        assert!(lib.contains("    day25::register(&mut registry);\n    year2023::register(&mut registry);\n    registry\n}\n"));
+        assert!(lib.ends_with("    fn test_day25() { test_helper(25); }\n    #[test]\n    fn test_2023_day5() { test_year_helper(2023, 5); }\n\
+                               \x20   #[test]\n    fn test_2023_day1() { test_year_helper(2023, 1); }\n}\n"));
+        assert_eq!(lib.matches("    year2023::register(&mut registry);\n").count(), 1);
+        let year_mod = read("src/year2023/mod.rs");
+        assert!(year_mod.contains("pub mod day1;\npub mod day5;\n") && year_mod.contains("    day1::register(registry);\n    day5::register"));
+        assert!(read("src/year2023/day5.rs").contains("SolverInfo::new(2023, 5, \"day5\""));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_day1() { test_helper(1); }
+    #[test]
+    fn test_day2() { test_helper(2); }
+    #[test]
+    fn test_day3() { test_helper(3); }
+    #[test]
+    fn test_day4() { test_helper(4); }
+    #[test]
+    fn test_day5() { test_helper(5); }
+    #[test]
+    fn test_day6() { test_helper(6); }
+    #[test]
+    fn test_day7() { test_helper(7); }
+    #[test]
+    fn test_day8() { test_helper(8); }
+    #[test]
+    fn test_day9() { test_helper(9); }
+    #[test]
+    fn test_day10() { test_helper(10); }
+    #[test]
+    fn test_day11() { test_helper(11); }
+    #[test]
+    fn test_day12() { test_helper(12); }
+    #[test]
+    fn test_day13() { test_helper(13); }
+    #[test]
+    fn test_day14() { test_helper(14); }
+    #[test]
+    fn test_day15() { test_helper(15); }
+    #[test]
+    fn test_day16() { test_helper(16); }
+    #[test]
+    fn test_day17() { test_helper(17); }
+    #[test]
+    fn test_day18() { test_helper(18); }
+    #[test]
+    fn test_day19() { test_helper(19); }
+    #[test]
+    fn test_day20() { test_helper(20); }
+    #[test]
+    fn test_day21() { test_helper(21); }
+    #[test]
+    fn test_day22() { test_helper(22); }
+    #[test]
+    fn test_day23() { test_helper(23); }
+    #[test]
+    fn test_day24() { test_helper(24); }
+    #[test]
+    fn test_day25() { test_helper(25); }
+}
@@ -0,0 +1,52 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much diagnostic output to write to stderr.  Answers and reports always go to stdout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Nothing (the default).
+    Quiet,
+    /// Progress through the puzzles, e.g. timings and intermediate results ('-v').
+    Progress,
+    /// Per-step traces from the solvers, e.g. the elves' positions after each round ('-vv').
+    Trace,
+}
+
+static LEVEL:AtomicU8 = AtomicU8::new(Level::Quiet as u8);
+
+pub fn set_level(level:Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// The level for a count of '-v' options.
+pub fn level_for_verbosity(verbosity:u8) -> Level {
+    match verbosity {
+        0 => Level::Quiet,
+        1 => Level::Progress,
+        _ => Level::Trace,
+    }
+}
+
+/// True if messages at the level should be written.
+pub fn enabled(level:Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Write a progress message to stderr, if enabled.  The arguments aren't evaluated otherwise.
+#[macro_export]
+macro_rules! progress {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Progress) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Write a trace message to stderr, if enabled.  The arguments aren't evaluated otherwise.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
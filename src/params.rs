@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// Puzzle parameters that override a day's defaults, e.g. 'row=10' for day 15.  Each day that
+/// has parameters reads them into its own `Params` struct, which holds the defaults for the
+/// sample and real inputs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Overrides {
+    values: BTreeMap<String, String>,
+}
+
+impl Overrides {
+    pub fn new() -> Self {
+        Overrides::default()
+    }
+
+    /// Override the named parameter.
+    pub fn set(mut self, name:&str, value:impl ToString) -> Self {
+        self.values.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Names of the overridden parameters.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(|name| name.as_str())
+    }
+
+    /// The named parameter, or 'default' if it isn't overridden.
+    pub fn get<T: FromStr>(&self, day:u8, name:&str, default:T) -> Result<T> {
+        match self.values.get(name) {
+            Some(value) => value.parse::<T>().map_err(|_| Error::Param {day, name: name.to_string(),
+                                                                         message: format!("invalid value '{}'", value)}),
+            None => Ok(default),
+        }
+    }
+
+    /// Check that every overridden parameter is one of the day's parameters.
+    pub fn check(&self, day:u8, known:&[&str]) -> Result<()> {
+        match self.names().find(|name| !known.contains(name)) {
+            Some(name) => {
+                let message = if known.is_empty() {"the day has no parameters".to_string()}
+                              else {format!("no such parameter, expected one of {}", known.join(", "))};
+                Err(Error::Param {day, name: name.to_string(), message})
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+/// A single override, parsed from 'name=value' (e.g. on the command line).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for Param {
+    type Err = String;
+
+    fn from_str(s:&str) -> std::result::Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((name, value)) if !name.is_empty() => Ok(Param {name: name.to_string(), value: value.to_string()}),
+            _ => Err(format!("invalid parameter '{}', expected name=value", s)),
+        }
+    }
+}
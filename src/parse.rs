@@ -0,0 +1,157 @@
+use std::str::FromStr;
+
+use crate::error::{end_of_input, Line, Result};
+
+// A piece of a scan pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token<'p> {
+    /// Text that must match exactly.
+    Literal(&'p str),
+    /// One or more spaces, matching any run of whitespace.
+    Space,
+    /// '{}', matching everything up to the next token.
+    Field,
+}
+
+fn tokens(pattern:&str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = pattern;
+    while !rest.is_empty() {
+        let (token, len) = if rest.starts_with("{}") {
+            (Token::Field, 2)
+        } else if rest.starts_with(' ') {
+            (Token::Space, rest.len() - rest.trim_start_matches(' ').len())
+        } else {
+            let len = rest.find([' ', '{']).filter(|len| *len > 0).unwrap_or(rest.len());
+            (Token::Literal(&rest[..len]), len)
+        };
+        tokens.push(token);
+        rest = &rest[len..];
+    }
+    tokens
+}
+
+impl<'a> Line<'a> {
+    /// Match the line against a pattern like 'move {} from {} to {}', returning the text of each
+    /// '{}' field.  A space in the pattern matches any amount of whitespace, everything else must
+    /// match exactly.  A field runs up to the next part of the pattern (or the end of the line).
+    pub fn scan_fields(&self, pattern:&str) -> Result<Vec<&'a str>> {
+        let tokens = tokens(pattern);
+        let mut fields = Vec::new();
+        let mut rest = self.text;
+        for (i, token) in tokens.iter().enumerate() {
+            rest = match token {
+                Token::Literal(literal) => {
+                    rest.strip_prefix(literal).ok_or_else(|| self.error_at(rest, format!("expected '{}'", literal)))?
+                },
+                Token::Space => {
+                    let trimmed = rest.trim_start();
+                    if trimmed.len() == rest.len() {
+                        return Err(self.error_at(rest, "expected a space"));
+                    }
+                    trimmed
+                },
+                Token::Field => {
+                    let end = match tokens.get(i + 1) {
+                        Some(Token::Literal(literal)) => {
+                            rest.find(literal).ok_or_else(|| self.error_at(rest, format!("expected '{}'", literal)))?
+                        },
+                        Some(Token::Space) => rest.find(char::is_whitespace).unwrap_or(rest.len()),
+                        Some(Token::Field) => panic!("adjacent fields in pattern '{}'", pattern),
+                        None => rest.len(),
+                    };
+                    fields.push(&rest[..end]);
+                    &rest[end..]
+                },
+            };
+        }
+        if !rest.is_empty() {
+            return Err(self.error_at(rest, "unexpected text"));
+        }
+        Ok(fields)
+    }
+
+    /// Match the line against a pattern with exactly 'N' fields (see 'scan_fields').
+    pub fn scan<const N: usize>(&self, pattern:&str) -> Result<[&'a str; N]> {
+        let fields = self.scan_fields(pattern)?;
+        assert_eq!(fields.len(), N, "pattern '{}' should have {} fields", pattern, N);
+        Ok(fields.try_into().unwrap())
+    }
+
+    /// Parse each item of the 'separator' separated list in 'field' (a slice of this line's text).
+    pub fn list<T: FromStr>(&self, field:&'a str, separator:&str) -> Result<Vec<T>> {
+        field.split(separator).map(|item| self.parse::<T>(item)).collect()
+    }
+}
+
+/// Match a line against a pattern (see `Line::scan_fields`), parsing each field as the given type,
+/// e.g. `scan!(line, "move {} from {} to {}", usize, usize, usize)?`.
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $pattern:expr, $($t:ty),+ $(,)?) => {{
+        let line = &$line;
+        line.scan_fields($pattern).and_then(|fields| {
+            assert_eq!(fields.len(), [$(stringify!($t)),+].len(), "pattern '{}' has the wrong number of fields", $pattern);
+            let mut fields = fields.into_iter();
+            Ok(($(line.parse::<$t>(fields.next().unwrap())?,)+))
+        })
+    }};
+}
+
+/// Lines of input between blank lines.
+#[derive(Clone, Debug)]
+pub struct Block<'a> {
+    pub day: u8,
+    pub lines: Vec<Line<'a>>,
+    // The blank line after the block, None for the last block.
+    end: Option<Line<'a>>,
+    input: &'a [String],
+}
+
+impl<'a> Block<'a> {
+    /// The block's line 'i' (from 0), an error saying what was expected if the block ends first.
+    pub fn line(&self, i:usize, expected:&str) -> Result<Line<'a>> {
+        match (self.lines.get(i), self.end) {
+            (Some(line), _) => Ok(*line),
+            (None, Some(blank)) => Err(blank.error(format!("unexpected blank line, expected {}", expected))),
+            (None, None) => Err(end_of_input(self.day, self.input, expected)),
+        }
+    }
+
+    /// Check the block has no more than 'len' lines.
+    pub fn check_len(&self, len:usize) -> Result<()> {
+        match self.lines.get(len) {
+            Some(line) => Err(line.error("expected a blank line")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Split the input into blocks at each blank line (so consecutive blank lines give empty blocks).
+pub fn blocks(day:u8, lines:&[String]) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut block = Vec::new();
+    for line in Line::enumerate(day, lines) {
+        if line.text.is_empty() {
+            blocks.push(Block {day, lines: std::mem::take(&mut block), end: Some(line), input: lines});
+        } else {
+            block.push(line);
+        }
+    }
+    blocks.push(Block {day, lines: block, end: None, input: lines});
+    blocks
+}
+
+/// Split the input into exactly 'N' blocks, e.g. a map and then a list of moves.  'names'
+/// describes each block, for the error if the input ends before it.
+pub fn sections<'a, const N: usize>(day:u8, lines:&'a [String], names:[&str; N]) -> Result<[Block<'a>; N]> {
+    let blocks = blocks(day, lines);
+    if blocks.len() > N {
+        // Only the last block has no blank line after it.
+        return Err(blocks[N - 1].end.unwrap().error("unexpected blank line"));
+    }
+    if blocks.len() < N {
+        return Err(end_of_input(day, lines, names[blocks.len()]));
+    }
+    Ok(blocks.try_into().unwrap())
+}
@@ -0,0 +1,218 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Signed integer types that can be used for point coordinates.
+pub trait Coordinate: Copy + Ord + Default + std::fmt::Debug + std::hash::Hash
+                      + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+    const MINUS_ONE: Self;
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {$(
+        impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MINUS_ONE: Self = -1;
+            fn abs(self) -> Self { <$t>::abs(self) }
+            fn signum(self) -> Self { <$t>::signum(self) }
+        }
+    )*};
+}
+
+coordinate!(i8, i16, i32, i64, isize);
+
+/// A 2D point or vector, with y increasing downwards (as in the puzzle maps).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub const ORIGIN: Self = Point2 {x: T::ZERO, y: T::ZERO};
+
+    pub fn new(x:T, y:T) -> Self {
+        Point2 {x, y}
+    }
+
+    pub fn dot(self, other:Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The z component of the 3D cross product.
+    pub fn cross(self, other:Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Rotate 90 degrees anticlockwise, as seen on the map (e.g. right becomes up).
+    pub fn rotate_left(self) -> Self {
+        Point2 {x: self.y, y: -self.x}
+    }
+
+    /// Rotate 90 degrees clockwise, as seen on the map (e.g. right becomes down).
+    pub fn rotate_right(self) -> Self {
+        Point2 {x: -self.y, y: self.x}
+    }
+
+    /// Each component's sign, e.g. a single step towards a point.
+    pub fn signum(self) -> Self {
+        Point2 {x: self.x.signum(), y: self.y.signum()}
+    }
+
+    pub fn manhattan(self, other:Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance when diagonal steps are allowed (i.e. the number of king moves).
+    pub fn chebyshev(self, other:Self) -> T {
+        std::cmp::max((self.x - other.x).abs(), (self.y - other.y).abs())
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y):(T, T)) -> Self {
+        Point2 {x, y}
+    }
+}
+
+/// A 3D point or vector.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const ORIGIN: Self = Point3 {x: T::ZERO, y: T::ZERO, z: T::ZERO};
+
+    /// Unit vectors along each axis, in both directions.
+    pub const AXES: [Self; 6] = [Point3 {x: T::ONE, y: T::ZERO, z: T::ZERO}, Point3 {x: T::ZERO, y: T::ONE, z: T::ZERO},
+                                 Point3 {x: T::ZERO, y: T::ZERO, z: T::ONE}, Point3 {x: T::ZERO, y: T::ZERO, z: T::MINUS_ONE},
+                                 Point3 {x: T::ZERO, y: T::MINUS_ONE, z: T::ZERO}, Point3 {x: T::MINUS_ONE, y: T::ZERO, z: T::ZERO}];
+
+    pub fn new(x:T, y:T, z:T) -> Self {
+        Point3 {x, y, z}
+    }
+
+    pub fn dot(self, other:Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other:Self) -> Self {
+        Point3 {x: self.y * other.z - self.z * other.y,
+                y: self.z * other.x - self.x * other.z,
+                z: self.x * other.y - self.y * other.x}
+    }
+
+    /// Rotate 90 degrees anticlockwise about the axis (a unit vector at right angles to this one).
+    pub fn rotate_left(self, axis:Self) -> Self {
+        axis.cross(self)
+    }
+
+    /// Rotate 90 degrees clockwise about the axis (a unit vector at right angles to this one).
+    pub fn rotate_right(self, axis:Self) -> Self {
+        self.cross(axis)
+    }
+
+    pub fn manhattan(self, other:Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other:Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs()).max((self.z - other.z).abs())
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z):(T, T, T)) -> Self {
+        Point3 {x, y, z}
+    }
+}
+
+// The component-wise operators are the same for both types.
+macro_rules! operators {
+    ($point:ident, $($field:ident),*) => {
+        impl<T: Coordinate> Add for $point<T> {
+            type Output = Self;
+            fn add(self, other:Self) -> Self { $point {$($field: self.$field + other.$field),*} }
+        }
+
+        impl<T: Coordinate> Sub for $point<T> {
+            type Output = Self;
+            fn sub(self, other:Self) -> Self { $point {$($field: self.$field - other.$field),*} }
+        }
+
+        impl<T: Coordinate> Neg for $point<T> {
+            type Output = Self;
+            fn neg(self) -> Self { $point {$($field: -self.$field),*} }
+        }
+
+        /// Scale by a constant.
+        impl<T: Coordinate> Mul<T> for $point<T> {
+            type Output = Self;
+            fn mul(self, scale:T) -> Self { $point {$($field: self.$field * scale),*} }
+        }
+
+        impl<T: Coordinate> AddAssign for $point<T> {
+            fn add_assign(&mut self, other:Self) { *self = *self + other; }
+        }
+
+        impl<T: Coordinate> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other:Self) { *self = *self - other; }
+        }
+    };
+}
+
+operators!(Point2, x, y);
+operators!(Point3, x, y, z);
+
+/// A direction on a map, with y increasing downwards.  In clockwise order, starting from the
+/// right (e.g. day 22's facing values).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Right, Direction::Down, Direction::Left, Direction::Up];
+
+    /// A single step in the direction.
+    pub fn offset<T: Coordinate>(self) -> Point2<T> {
+        match self {
+            Direction::Right => Point2::new(T::ONE, T::ZERO),
+            Direction::Down => Point2::new(T::ZERO, T::ONE),
+            Direction::Left => Point2::new(T::MINUS_ONE, T::ZERO),
+            Direction::Up => Point2::new(T::ZERO, T::MINUS_ONE),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The direction for a letter or arrow, e.g. 'R' or '>'.
+    pub fn from_char(c:char) -> Option<Self> {
+        match c {
+            'R' | '>' => Some(Direction::Right),
+            'D' | 'v' => Some(Direction::Down),
+            'L' | '<' => Some(Direction::Left),
+            'U' | '^' => Some(Direction::Up),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::BTreeMap;
+
+use crate::compare::Generator;
+use crate::solver::Solver;
+
+/// Collection of solvers keyed by (year, day).  A day may have several implementations, the
+/// first one registered is the default.
+#[derive(Default)]
+pub struct Registry {
+    solvers: BTreeMap<(u16, u8), Vec<Box<dyn Solver>>>,
+    generators: BTreeMap<(u16, u8), Generator>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    pub fn register<S: Solver + 'static>(&mut self, solver:S) {
+        let key = (solver.info().year, solver.info().day);
+        self.solvers.entry(key).or_default().push(Box::new(solver));
+    }
+
+    /// Make random inputs for the day with 'generator', to compare its implementations on.
+    pub fn register_generator(&mut self, year:u16, day:u8, generator:Generator) {
+        self.generators.insert((year, day), generator);
+    }
+
+    /// The day's input generator, if it has one.
+    pub fn generator(&self, year:u16, day:u8) -> Option<Generator> {
+        self.generators.get(&(year, day)).copied()
+    }
+
+    /// The default solver for the given day.
+    pub fn get(&self, year:u16, day:u8) -> Option<&dyn Solver> {
+        self.implementations(year, day).next()
+    }
+
+    /// Find a solver for the day by its name (e.g. 'day2_old').
+    pub fn get_named(&self, year:u16, day:u8, name:&str) -> Option<&dyn Solver> {
+        self.implementations(year, day).find(|s| s.info().name == name)
+    }
+
+    /// All implementations registered for the given day.
+    pub fn implementations(&self, year:u16, day:u8) -> impl Iterator<Item = &dyn Solver> {
+        self.solvers.get(&(year, day)).into_iter().flatten().map(|s| s.as_ref())
+    }
+
+    /// The default solver for every registered day, in (year, day) order.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Solver> {
+        self.solvers.values().filter_map(|s| s.first()).map(|s| s.as_ref())
+    }
+
+    /// Every registered solver, including alternate implementations.
+    pub fn iter_all(&self) -> impl Iterator<Item = &dyn Solver> {
+        self.solvers.values().flatten().map(|s| s.as_ref())
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::runner::{DayRun, Status};
+
+/// How to print the results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s:&str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format '{}', expected json, csv or text", s)),
+        }
+    }
+}
+
+/// One part of a puzzle run, as reported in the JSON and CSV output.
+pub struct Record<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub solver: &'a str,
+    /// 'sample', 'real', 'file', 'stdin', ...
+    pub input: &'a str,
+    /// None if the part couldn't be run.
+    pub answer: Option<&'a Answer>,
+    pub answer_type: &'a str,
+    pub parse_ns: u128,
+    pub duration_ns: u128,
+    /// 'pass', 'fail', 'unknown' or 'error'.
+    pub status: &'a str,
+    /// Why the part couldn't be run, empty unless the status is 'error'.
+    pub error: String,
+}
+
+const FIELDS:[&str; 11] = ["year", "day", "part", "solver", "input", "answer", "answer_type",
+                           "parse_ns", "duration_ns", "status", "error"];
+
+impl Record<'_> {
+    // Field values as (text, JSON), in the same order as 'FIELDS'.
+    fn values(&self) -> [(String, String); 11] {
+        let number = |n:String| (n.clone(), n);
+        let string = |s:&str| (s.to_string(), json_string(s));
+        [number(self.year.to_string()), number(self.day.to_string()), number(self.part.to_string()),
+         string(self.solver), string(self.input),
+         self.answer.map_or_else(|| string(""), |answer| (answer.to_string(), answer.to_json())),
+         string(self.answer_type), number(self.parse_ns.to_string()),
+         number(self.duration_ns.to_string()), string(self.status), string(&self.error)]
+    }
+}
+
+/// A record for each part of each run.  Runs that failed (e.g. the input couldn't be read) have
+/// a record for each part that was requested, and parts that failed have no answer.
+pub fn records(runs:&[DayRun]) -> Vec<Record<'_>> {
+    let mut records = Vec::new();
+    for run in runs {
+        let info = &run.info;
+        let record = |part:u8| Record {year: info.year, day: info.day, part, solver: info.name, input: run.input,
+                                       answer: None, answer_type: info.answer_types[part as usize - 1].as_str(),
+                                       parse_ns: 0, duration_ns: 0, status: "error", error: String::new()};
+        match &run.outcome {
+            Ok((parse_time, parts)) => {
+                for part in parts {
+                    let run = Record {parse_ns: parse_time.as_nanos(), duration_ns: part.time.as_nanos(), status: part.status.as_str(),
+                                      ..record(part.part)};
+                    records.push(match &part.answer {
+                        Ok(answer) => Record {answer: Some(answer), answer_type: answer.answer_type().as_str(), ..run},
+                        Err(error) => Record {error: error.to_string(), ..run},
+                    });
+                }
+            },
+            Err(error) => {
+                for part in &run.parts {
+                    records.push(Record {error: error.to_string(), ..record(*part)});
+                }
+            },
+        }
+    }
+    records
+}
+
+/// The string as a quoted JSON string.
+pub fn json_string(s:&str) -> String {
+    let mut json = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// A JSON array of records, one per line.
+pub fn json(runs:&[DayRun]) -> String {
+    let lines = records(runs).iter().map(|record| {
+        let fields = FIELDS.iter().zip(record.values())
+                           .map(|(name, (_, json))| format!("\"{}\": {}", name, json))
+                           .collect::<Vec<String>>();
+        format!("  {{{}}}", fields.join(", "))
+    }).collect::<Vec<String>>();
+    if lines.is_empty() {"[]\n".to_string()} else {format!("[\n{}\n]\n", lines.join(",\n"))}
+}
+
+fn csv_field(s:&str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// CSV with a header row, then a row per record.  Multi-line answers are quoted.
+pub fn csv(runs:&[DayRun]) -> String {
+    let mut csv = FIELDS.join(",") + "\n";
+    for record in records(runs) {
+        let fields = record.values().map(|(text, _)| csv_field(&text));
+        csv += &(fields.join(",") + "\n");
+    }
+    csv
+}
+
+fn format_duration(time:Duration) -> String {
+    format!("{:.3}ms", time.as_secs_f64() * 1000.0)
+}
+
+// Answers are shown on a single line, pictures (e.g. the day 10 CRT) don't fit in the table
+// unless their letters can be read.
+fn format_answer(answer:&Answer) -> String {
+    match answer {
+        Answer::Picture(_) => answer.ocr().unwrap_or_else(|| "<picture>".to_string()),
+        _ => answer.to_string(),
+    }
+}
+
+/// A table of the answers, whether they match the known answers and how long each step took.
+/// The total is the sum of the steps' times, so more than the elapsed time if the runs were in
+/// parallel.
+pub fn text_table(runs:&[DayRun]) -> String {
+    let mut rows = vec![["Day", "Name", "Input", "Parse", "Part 1", "", "Time", "Part 2", "", "Time"].map(String::from).to_vec()];
+    let mut total = Duration::ZERO;
+    for run in runs {
+        let mut row = vec![run.info.day.to_string(), run.info.name.to_string(), run.input.to_string()];
+        match &run.outcome {
+            Ok((parse_time, parts)) => {
+                row.push(format_duration(*parse_time));
+                total += *parse_time;
+                for part_number in [1, 2] {
+                    if let Some(part) = parts.iter().find(|p| p.part == part_number) {
+                        row.push(part.answer.as_ref().map_or_else(|error| format!("error: {}", error), format_answer));
+                        row.push(match part.status {Status::Pass => "ok", Status::Fail => "FAIL", Status::Unknown => "?", Status::Error => "ERROR"}.to_string());
+                        row.push(format_duration(part.time));
+                        total += part.time;
+                    } else {
+                        row.extend(["-", "", ""].map(String::from));
+                    }
+                }
+            },
+            Err(error) => row.push(format!("error: {}", error)),
+        }
+        rows.push(row);
+    }
+
+    let mut widths = vec![0; rows[0].len()];
+    for row in &rows {
+        // Errors run over the remaining columns.
+        for (i, cell) in row.iter().enumerate().take(if row.len() < widths.len() {row.len() - 1} else {row.len()}) {
+            widths[i] = std::cmp::max(widths[i], cell.len());
+        }
+    }
+
+    let mut table = String::new();
+    for row in &rows {
+        let cells = row.iter().zip(&widths).map(|(cell, width)| format!("{:<width$}", cell, width = width)).collect::<Vec<String>>();
+        writeln!(table, "{}", cells.join("  ").trim_end()).unwrap();
+    }
+    let failed = runs.iter().filter(|r| !r.passed()).count();
+    writeln!(table, "Total: {} summed over {} runs ({} failed)", format_duration(total), runs.len(), failed).unwrap();
+    table
+}
+
+/// The runs in the given format.
+pub fn format(runs:&[DayRun], format:Format) -> String {
+    match format {
+        Format::Text => text_table(runs),
+        Format::Json => json(runs),
+        Format::Csv => csv(runs),
+    }
+}
@@ -0,0 +1,152 @@
+use std::cell::RefCell;
+use std::io::{IsTerminal, Write};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use crate::log;
+
+/// Receives progress updates from the solvers, e.g. to show how far a long search has got.
+pub trait Reporter: Send + Sync {
+    /// 'task' is what's running (e.g. 'day19 part 1 (real input)') and 'phase' what it's doing
+    /// (e.g. 'blueprint'), with how many are done, out of 'total' if that's known.
+    fn update(&self, task:&str, phase:&str, done:u64, total:Option<u64>);
+    /// The task has finished.
+    fn finish(&self, task:&str);
+}
+
+static REPORTER:RwLock<Option<Arc<dyn Reporter>>> = RwLock::new(None);
+
+thread_local! {
+    // The task running on this thread, if any.
+    static TASK:RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Send progress updates to the reporter, or nowhere for None (the default).
+pub fn set_reporter(reporter:Option<Arc<dyn Reporter>>) {
+    *REPORTER.write().unwrap() = reporter;
+}
+
+/// Report progress from a solver, e.g. `update("rock", 4200, Some(10000))`.  Ignored unless
+/// there's a reporter, and a task running on this thread (see 'task').  Cheap, but meant for
+/// steps that take a while (e.g. each blueprint), not every step of a search.
+pub fn update(phase:&str, done:u64, total:Option<u64>) {
+    let Some(reporter) = REPORTER.read().unwrap().clone() else {return};
+    TASK.with_borrow(|task| {
+        if let Some(task) = task {
+            reporter.update(task, phase, done, total);
+        }
+    });
+}
+
+/// Run 'f' as the named task, so that its progress updates are reported as part of it.
+pub fn task<T>(name:&str, f:impl FnOnce() -> T) -> T {
+    let previous = TASK.replace(Some(name.to_string()));
+    let result = f();
+    TASK.set(previous);
+    if let Some(reporter) = REPORTER.read().unwrap().clone() {
+        reporter.finish(name);
+    }
+    result
+}
+
+/// The reporter for stderr: a progress bar if it's a terminal, otherwise a line every few
+/// seconds when progress messages are enabled ('-v').  None if neither.
+pub fn for_stderr() -> Option<Arc<dyn Reporter>> {
+    if std::io::stderr().is_terminal() {
+        Some(Arc::new(Bar::new()))
+    } else if log::enabled(log::Level::Progress) {
+        Some(Arc::new(Lines::new(Duration::from_secs(5))))
+    } else {
+        None
+    }
+}
+
+// E.g. "blueprint 3/30 (10%)", or "minute 211" when there's no total.
+fn describe(phase:&str, done:u64, total:Option<u64>) -> String {
+    match total {
+        Some(total) if total > 0 => format!("{} {}/{} ({}%)", phase, done, total, done * 100 / total),
+        _ => format!("{} {}", phase, done),
+    }
+}
+
+/// A progress bar, redrawn in place on the terminal (at most every 100ms) and cleared when the
+/// task finishes.  With several tasks at once it shows whichever updated last.
+pub struct Bar {
+    last_drawn: Mutex<Option<Instant>>,
+}
+
+impl Bar {
+    const WIDTH:u64 = 30;
+
+    pub fn new() -> Self {
+        Bar {last_drawn: Mutex::new(None)}
+    }
+}
+
+impl Default for Bar {
+    fn default() -> Self {
+        Bar::new()
+    }
+}
+
+impl Reporter for Bar {
+    fn update(&self, task:&str, phase:&str, done:u64, total:Option<u64>) {
+        let mut last_drawn = self.last_drawn.lock().unwrap();
+        if last_drawn.is_some_and(|last| last.elapsed() < Duration::from_millis(100)) {
+            return;
+        }
+        *last_drawn = Some(Instant::now());
+        let bar = match total {
+            Some(total) if total > 0 => {
+                let filled = (done.min(total) * Bar::WIDTH / total) as usize;
+                format!("[{}{}] ", "#".repeat(filled), ".".repeat(Bar::WIDTH as usize - filled))
+            },
+            _ => String::new(),
+        };
+        // Clear the rest of the line, in case the last update was longer.
+        eprint!("\r{}: {}{}\x1b[K", task, bar, describe(phase, done, total));
+        std::io::stderr().flush().ok();
+    }
+
+    fn finish(&self, _task:&str) {
+        let mut last_drawn = self.last_drawn.lock().unwrap();
+        if last_drawn.take().is_some() {
+            eprint!("\r\x1b[K");
+            std::io::stderr().flush().ok();
+        }
+    }
+}
+
+/// A line on stderr for each task at most every 'interval', e.g. for logs.
+pub struct Lines {
+    interval: Duration,
+    // When each running task was last reported.
+    last_written: Mutex<Vec<(String, Instant)>>,
+}
+
+impl Lines {
+    pub fn new(interval:Duration) -> Self {
+        Lines {interval, last_written: Mutex::new(Vec::new())}
+    }
+}
+
+impl Reporter for Lines {
+    fn update(&self, task:&str, phase:&str, done:u64, total:Option<u64>) {
+        let mut last_written = self.last_written.lock().unwrap();
+        let now = Instant::now();
+        match last_written.iter_mut().find(|(name, _)| name == task) {
+            Some((_, last)) if now.duration_since(*last) < self.interval => return,
+            Some((_, last)) => *last = now,
+            // The first update is only written once the task has been running for a while.
+            None => {
+                last_written.push((task.to_string(), now));
+                return;
+            },
+        }
+        eprintln!("{}: {}", task, describe(phase, done, total));
+    }
+
+    fn finish(&self, task:&str) {
+        self.last_written.lock().unwrap().retain(|(name, _)| name != task);
+    }
+}
@@ -0,0 +1,165 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::answers::Answers;
+use crate::cancel::Cancel;
+use crate::error::Result;
+use crate::input::InputSource;
+use crate::params::Overrides;
+use crate::progress;
+use crate::registry::Registry;
+use crate::reporter;
+use crate::solver::{Solver, SolverInfo};
+
+/// Days to run, e.g. '5', '1..=12', '1..13' or 'all'.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DaySelection {
+    One(u8),
+    Range(RangeInclusive<u8>),
+}
+
+impl DaySelection {
+    pub fn contains(&self, day:u8) -> bool {
+        match self {
+            DaySelection::One(one) => *one == day,
+            DaySelection::Range(range) => range.contains(&day),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s:&str) -> std::result::Result<Self, Self::Err> {
+        let day = |d:&str| d.trim().parse::<u8>().map_err(|_| format!("invalid day '{}'", d));
+        if s == "all" {
+            Ok(DaySelection::Range(1..=25))
+        } else if let Some((start, end)) = s.split_once("..=") {
+            Ok(DaySelection::Range(day(start)?..=day(end)?))
+        } else if let Some((start, end)) = s.split_once("..") {
+            Ok(DaySelection::Range(day(start)?..=day(end)?.saturating_sub(1)))
+        } else {
+            Ok(DaySelection::One(day(s)?))
+        }
+    }
+}
+
+/// How an answer compares with the known answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+    /// The part failed (or timed out) without an answer.
+    Error,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Unknown => "unknown",
+            Status::Error => "error",
+        }
+    }
+}
+
+/// The answer to one part of a puzzle (or why it has none) and how long it took.
+pub struct PartRun {
+    /// 1 or 2.
+    pub part: u8,
+    pub answer: Result<Answer>,
+    pub time: Duration,
+    pub status: Status,
+}
+
+/// Result of running a day's puzzle on one input.
+pub struct DayRun {
+    pub info: SolverInfo,
+    pub sample: bool,
+    /// Kind of input, e.g. 'sample', 'real' or 'stdin'.
+    pub input: &'static str,
+    /// Parts that were requested.
+    pub parts: Vec<u8>,
+    /// Time to parse the input and the results for each part, or why it couldn't be read.
+    pub outcome: Result<(Duration, Vec<PartRun>)>,
+}
+
+impl DayRun {
+    /// True if the input was read and every part gave an answer that isn't wrong.
+    pub fn passed(&self) -> bool {
+        self.outcome.as_ref().is_ok_and(|(_, parts)| parts.iter().all(|p| matches!(p.status, Status::Pass | Status::Unknown)))
+    }
+}
+
+/// Run the parts (1 and/or 2) of the puzzle, timing reading and parsing the input and each part
+/// separately.  The answers are checked against the known answers for the input file, unless
+/// the puzzle parameters are overridden.  Each part is stopped with an error if it takes longer
+/// than 'timeout', which doesn't stop the other part running.
+pub fn run_solver(solver:&dyn Solver, input:InputSource, sample:bool, parts:&[u8], overrides:&Overrides, answers:&Answers,
+                  timeout:Option<Duration>) -> DayRun {
+    let info = solver.info();
+    let kind = input.kind(sample);
+    let file_name = input.file_name(info.year, info.day, sample).filter(|_| overrides.names().next().is_none());
+    let run = || {
+        let start = Instant::now();
+        let parsed = solver.read(input, sample, overrides)?;
+        let parse_time = start.elapsed();
+        progress!("{} ({} input): read and parsed in {:?}", info.name, kind, parse_time);
+
+        let part = |part:u8| {
+            let start = Instant::now();
+            let cancel = Cancel::with_timeout(timeout);
+            let answer = reporter::task(&format!("{} part {} ({} input)", info.name, part, kind), || {
+                if part == 2 {solver.part2(parsed.as_ref(), &cancel)} else {solver.part1(parsed.as_ref(), &cancel)}
+            });
+            let time = start.elapsed();
+            let expected = file_name.as_ref().and_then(|name| answers.get(info.year, info.day, name, part));
+            let status = match (&answer, expected) {
+                (Err(_), _) => Status::Error,
+                (Ok(answer), Some(expected)) if answer.matches(expected) => Status::Pass,
+                (Ok(_), Some(_)) => Status::Fail,
+                (Ok(_), None) => Status::Unknown,
+            };
+            progress!("{} ({} input): part {} took {:?}, {}", info.name, kind, part, time, status.as_str());
+            PartRun {part, answer, time, status}
+        };
+        Ok((parse_time, parts.iter().map(|p| part(*p)).collect()))
+    };
+    DayRun {info: info.clone(), sample, input: kind, parts: parts.to_vec(), outcome: run()}
+}
+
+/// Run the default solver for each selected day, on the sample and then the real input.  The
+/// runs are spread over 'jobs' threads (all the available cores for 0), but are returned in the
+/// same order as when run one at a time.  Parts that take longer than 'timeout' are stopped.
+pub fn run_days(registry:&Registry, year:u16, days:&DaySelection, answers:&Answers, jobs:usize, timeout:Option<Duration>) -> Vec<DayRun> {
+    let tasks = registry.iter().filter(|s| s.info().year == year && days.contains(s.info().day))
+                        .flat_map(|solver| [true, false].map(|sample| (solver, sample)))
+                        .collect::<Vec<(&dyn Solver, bool)>>();
+    let run = |(solver, sample):(&dyn Solver, bool)| run_solver(solver, InputSource::Default, sample, &[1, 2], &Overrides::new(), answers, timeout);
+    let jobs = if jobs == 0 {std::thread::available_parallelism().map_or(1, |n| n.get())} else {jobs};
+    if jobs == 1 {
+        return tasks.into_iter().map(run).collect();
+    }
+
+    // Each thread takes the next task until there are none left.
+    let next = AtomicUsize::new(0);
+    let mut runs = std::thread::scope(|scope| {
+        let threads = (0..jobs.min(tasks.len())).map(|_| scope.spawn(|| {
+            let mut runs = Vec::new();
+            loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(task) = tasks.get(index) else {break};
+                runs.push((index, run(*task)));
+            }
+            runs
+        })).collect::<Vec<_>>();
+        threads.into_iter().flat_map(|thread| thread.join().unwrap()).collect::<Vec<(usize, DayRun)>>()
+    });
+    runs.sort_by_key(|(index, _)| *index);
+    runs.into_iter().map(|(_, run)| run).collect()
+}
@@ -0,0 +1,197 @@
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{Answers, ANSWERS_FILE};
+use crate::error::{Error, Result};
+use crate::input::input_path;
+
+// The new day's module, '{year}' is the year expression used in the solver info.
+const TEMPLATE:&str = r#"use crate::error::Result;
+use crate::registry::Registry;
+use crate::solver::{AnswerType, DaySolver, SolverInfo};
+
+/// The puzzle input lines.
+pub type Input = Vec<String>;
+
+pub fn parse(lines:&[String]) -> Result<Input> {
+    Ok(lines.to_vec())
+}
+
+pub fn part1(input:&Input) -> u64 {
+    input.len() as u64
+}
+
+pub fn part2(input:&Input) -> u64 {
+    input.len() as u64
+}
+
+pub fn register(registry:&mut Registry) {
+    registry.register(DaySolver {
+        info: SolverInfo::new({year}, {day}, "day{day}", "{title}", [AnswerType::Integer, AnswerType::Integer]),
+        parse: |lines, _, _| parse(lines),
+        part1: |input, _| Ok(part1(input).into()),
+        part2: |input, _| Ok(part2(input).into()),
+    });
+}
+"#;
+
+// The lines that start the functions registering the days, and the crate's tests.
+const REGISTRY_FN:&str = "pub fn registry() -> Registry {";
+const YEAR_REGISTER_FN:&str = "pub fn register(registry:&mut Registry) {";
+const TESTS_MOD:&str = "mod tests {";
+
+/// Add a new day: its solver module (registered in the crate, with a test), empty sample and real
+/// input files and an answers manifest entry to fill in.  'root' is the crate's directory.
+/// Nothing is written if any of the day's files or entries already exist.  Returns the files
+/// written.
+pub fn new_day(root:&Path, year:u16, day:u8, title:&str) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::Scaffold {path: root.display().to_string(), message: format!("there's no day {}", day)});
+    }
+    let exists = |path:&Path| Error::Scaffold {path: path.display().to_string(), message: "it already exists".to_string()};
+
+    // The 2022 days are at the top of the crate, other years have a module each.
+    let (module_dir, year_expr) = if year == crate::YEAR {
+        (root.join("src"), "crate::YEAR".to_string())
+    } else {
+        (root.join("src").join(format!("year{}", year)), year.to_string())
+    };
+    let module = module_dir.join(format!("day{}.rs", day));
+    let inputs = [input_path(year, day, Some("sample")), input_path(year, day, None)].map(|path| root.join(path));
+    for path in std::iter::once(&module).chain(&inputs) {
+        if path.exists() {
+            return Err(exists(path));
+        }
+    }
+    let answers_path = root.join(ANSWERS_FILE);
+    let answers = if answers_path.exists() {Answers::load(&answers_path)?} else {Answers::default()};
+    if answers.contains_day(year, day) {
+        return Err(Error::Scaffold {path: answers_path.display().to_string(),
+                                    message: format!("there are already answers for day {} of {}", day, year)});
+    }
+
+    // Work out every edit before writing anything.
+    let lib_path = root.join("src").join("lib.rs");
+    let mut lib = read(&lib_path)?;
+    let mut edits = Vec::new();
+    let mod_line = format!("pub mod day{};", day);
+    let register_line = format!("    day{}::register(&mut registry);", day);
+    let mod_number = |line:&str| number(line, "pub mod day", ";");
+    let is_mod = |line:&str| line.starts_with("pub mod ");
+    if year == crate::YEAR {
+        lib = insert_day(&lib, &lib_path, day, &mod_line, Region::Items(REGISTRY_FN), mod_number)?;
+        lib = insert_day(&lib, &lib_path, day, &register_line, Region::Body(REGISTRY_FN),
+                         |line| number(line, "day", "::register(&mut registry);"))?;
+    } else {
+        // The year's 'register' is passed the registry, rather than owning it.
+        let register_line = format!("    day{}::register(registry);", day);
+        let year_mod = module_dir.join("mod.rs");
+        let text = if year_mod.exists() {
+            let text = read(&year_mod)?;
+            let text = insert_day(&text, &year_mod, day, &mod_line, Region::Items(YEAR_REGISTER_FN), mod_number)?;
+            insert_day(&text, &year_mod, day, &register_line, Region::Body(YEAR_REGISTER_FN), |line| number(line, "day", "::register(registry);"))?
+        } else {
+            lib = insert_after(&lib, &lib_path, &format!("pub mod year{};", year), Region::Items(REGISTRY_FN), is_mod)?;
+            lib = insert_after(&lib, &lib_path, &format!("    year{}::register(&mut registry);", year), Region::Body(REGISTRY_FN),
+                               |line| line.ends_with("::register(&mut registry);"))?;
+            format!("//! Advent of Code {} solutions.\n\nuse crate::registry::Registry;\n\n{}\n\n\
+                     /// All the solvers for the year.\n{}\n{}\n}}\n",
+                    year, mod_line, YEAR_REGISTER_FN, register_line)
+        };
+        edits.push((year_mod, text));
+    }
+    if year == crate::YEAR {
+        let test = format!("    #[test]\n    fn test_day{}() {{ test_helper({}); }}", day, day);
+        lib = insert_day(&lib, &lib_path, day, &test, Region::Body(TESTS_MOD),
+                         |line| line.trim().strip_prefix("fn test_day")?.split_once("()")?.0.parse().ok())?;
+    } else {
+        let test = format!("    #[test]\n    fn test_{}_day{}() {{ test_year_helper({}, {}); }}", year, day, year, day);
+        lib = insert_after(&lib, &lib_path, &test, Region::Body(TESTS_MOD),
+                           |line| line.trim_start().starts_with("fn test_") && line.ends_with("); }"))?;
+    }
+    edits.push((lib_path, lib));
+
+    let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+    edits.push((module, TEMPLATE.replace("{year}", &year_expr).replace("{day}", &day.to_string()).replace("{title}", &title)));
+    for path in &inputs {
+        edits.push((path.clone(), String::new()));
+    }
+    let file_name = |path:&Path| path.file_name().unwrap().to_string_lossy().to_string();
+    let entry = format!("\n[{}.{}]\n\"{}\" = {{}}  # fill in part1 and part2 from the puzzle\n\"{}\" = {{}}\n",
+                        year, day, file_name(&inputs[0]), file_name(&inputs[1]));
+    let manifest = if answers_path.exists() {read(&answers_path)?} else {String::new()};
+    edits.push((answers_path, manifest + &entry));
+
+    for (path, text) in &edits {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|source| Error::Io {path: dir.display().to_string(), source})?;
+        }
+        fs::write(path, text).map_err(|source| Error::Io {path: path.display().to_string(), source})?;
+    }
+    Ok(edits.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path:&Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| Error::Io {path: path.display().to_string(), source})
+}
+
+// The number between the prefix and suffix of the (trimmed) line, e.g. 5 for 'pub mod day5;'.
+fn number(line:&str, prefix:&str, suffix:&str) -> Option<u8> {
+    line.trim().strip_prefix(prefix)?.strip_suffix(suffix)?.parse().ok()
+}
+
+// The lines of a file that an edit may touch, so that lines elsewhere that look the same (e.g.
+// a test registering a day itself) are left alone.
+#[derive(Clone, Copy)]
+enum Region {
+    /// The lines before the function (or module) starting with this line, e.g. the 'mod' items.
+    Items(&'static str),
+    /// The lines between the one starting the function (or module) and its closing brace.
+    Body(&'static str),
+}
+
+impl Region {
+    // The region's line numbers in the text.
+    fn lines(&self, text:&str, path:&Path) -> Result<Range<usize>> {
+        let lines = text.lines().collect::<Vec<&str>>();
+        let start = match self {Region::Items(start) | Region::Body(start) => start};
+        let first = lines.iter().position(|line| line == start)
+            .ok_or_else(|| Error::Scaffold {path: path.display().to_string(), message: format!("can't find '{}'", start)})?;
+        Ok(match self {
+            Region::Items(_) => 0..first,
+            // The closing brace isn't indented.
+            Region::Body(_) => first + 1..lines.iter().skip(first).position(|line| *line == "}").map_or(lines.len(), |end| first + end),
+        })
+    }
+}
+
+// Insert the new line(s) after the last line in the region that 'matches'.
+fn insert_after(text:&str, path:&Path, new:&str, region:Region, matches:impl Fn(&str) -> bool) -> Result<String> {
+    let range = region.lines(text, path)?;
+    let last = text.lines().enumerate().filter(|(i, line)| range.contains(i) && matches(line)).map(|(i, _)| i).last();
+    insert_at(text, path, new, last.map(|last| last + 1))
+}
+
+// Insert the new line(s) among the region's lines for each day (found by 'day_of'), in day
+// order.
+fn insert_day(text:&str, path:&Path, day:u8, new:&str, region:Region, day_of:impl Fn(&str) -> Option<u8>) -> Result<String> {
+    let range = region.lines(text, path)?;
+    let days = text.lines().enumerate().map(|(i, line)| if range.contains(&i) {day_of(line)} else {None}).collect::<Vec<Option<u8>>>();
+    // After the previous day or, for the first day, before the next (and any lines that go with
+    // it, like a test's '#[test]', as the new day has them too).
+    let index = days.iter().rposition(|d| d.is_some_and(|d| d < day)).map(|i| i + 1)
+                    .or_else(|| days.iter().position(|d| d.is_some()).map(|i| i + 1 - new.lines().count()));
+    insert_at(text, path, new, index)
+}
+
+fn insert_at(text:&str, path:&Path, new:&str, index:Option<usize>) -> Result<String> {
+    let error = |message:String| Error::Scaffold {path: path.display().to_string(), message};
+    if text.lines().any(|line| line == new.lines().last().unwrap()) {
+        return Err(error(format!("it already has '{}'", new.trim())));
+    }
+    let index = index.ok_or_else(|| error(format!("nowhere to add '{}'", new.trim())))?;
+    let mut lines = text.lines().collect::<Vec<&str>>();
+    lines.insert(index, new);
+    Ok(lines.join("\n") + "\n")
+}
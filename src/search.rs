@@ -0,0 +1,185 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A graph, given by the nodes reachable in one step from each node and the cost of each step.
+/// Closures from a node to its neighbours are graphs, e.g. `|n:&u32| [(n + 1, 1), (n * 2, 1)]`.
+pub trait Graph<N> {
+    fn neighbours(&self, node:&N) -> impl IntoIterator<Item = (N, u64)>;
+}
+
+impl<N, I:IntoIterator<Item = (N, u64)>, F:Fn(&N) -> I> Graph<N> for F {
+    fn neighbours(&self, node:&N) -> impl IntoIterator<Item = (N, u64)> {
+        self(node)
+    }
+}
+
+/// The result of a search: the cost of reaching each node that was found, and the way there.
+#[derive(Clone, Debug)]
+pub struct Search<N> {
+    costs: HashMap<N, u64>,
+    // The node each node was reached from (none for the starts).
+    previous: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N:Clone + Eq + Hash> Search<N> {
+    fn new() -> Self {
+        Search {costs: HashMap::new(), previous: HashMap::new(), goal: None}
+    }
+
+    /// The goal the search stopped at, None if it didn't reach one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The cost of reaching the goal.
+    pub fn goal_cost(&self) -> Option<u64> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    /// The lowest cost of reaching the node from one of the starts, None if the search didn't
+    /// reach it (e.g. it stopped at the goal first).
+    pub fn cost(&self, node:&N) -> Option<u64> {
+        self.costs.get(node).copied()
+    }
+
+    /// Every node the search reached, with its cost.
+    pub fn costs(&self) -> impl Iterator<Item = (&N, u64)> {
+        self.costs.iter().map(|(node, cost)| (node, *cost))
+    }
+
+    /// The nodes on the way to the node, from a start to the node itself.
+    pub fn path(&self, node:&N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.previous.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path to the goal.
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.goal.as_ref().and_then(|goal| self.path(goal))
+    }
+}
+
+/// Breadth first search, for graphs where every step costs the same (the costs are ignored and
+/// each step counts as 1).  Stops at the first node that 'is_goal' accepts, or once every node
+/// reachable from the starts has been found.
+pub fn bfs<N:Clone + Eq + Hash>(graph:&impl Graph<N>, starts:impl IntoIterator<Item = N>,
+                                mut is_goal:impl FnMut(&N) -> bool) -> Search<N> {
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let cost = search.costs[&node] + 1;
+        for (neighbour, _) in graph.neighbours(&node) {
+            if !search.costs.contains_key(&neighbour) {
+                search.costs.insert(neighbour.clone(), cost);
+                search.previous.insert(neighbour.clone(), node.clone());
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    search
+}
+
+/// Lowest cost search, stopping at the first node that 'is_goal' accepts (or once every node
+/// reachable from the starts has been found).
+pub fn dijkstra<N:Clone + Eq + Hash>(graph:&impl Graph<N>, starts:impl IntoIterator<Item = N>,
+                                     is_goal:impl FnMut(&N) -> bool) -> Search<N> {
+    astar(graph, starts, is_goal, |_| 0)
+}
+
+/// Lowest cost search, guided towards the goal by 'heuristic'.  The heuristic must never
+/// overestimate the remaining cost (e.g. the Manhattan distance on a grid), or the path found
+/// might not be the cheapest.
+pub fn astar<N:Clone + Eq + Hash>(graph:&impl Graph<N>, starts:impl IntoIterator<Item = N>,
+                                  mut is_goal:impl FnMut(&N) -> bool, mut heuristic:impl FnMut(&N) -> u64) -> Search<N> {
+    let mut search = Search::new();
+    let mut nodes = Vec::new();
+    // (estimated total cost, cost so far, index in 'nodes'), so the nodes needn't be 'Ord'.
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            queue.push(Reverse((heuristic(&start), 0, nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let node = nodes[index].clone();
+        if search.costs[&node] < cost {
+            // Already reached more cheaply.
+            continue;
+        }
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for (neighbour, step) in graph.neighbours(&node) {
+            let new_cost = cost + step;
+            if search.costs.get(&neighbour).is_none_or(|previous| new_cost < *previous) {
+                search.costs.insert(neighbour.clone(), new_cost);
+                search.previous.insert(neighbour.clone(), node.clone());
+                queue.push(Reverse((new_cost + heuristic(&neighbour), new_cost, nodes.len())));
+                nodes.push(neighbour);
+            }
+        }
+    }
+    search
+}
+
+/// The lowest cost between every pair of the nodes (Floyd–Warshall), for the pairs that are
+/// connected.  Neighbours that aren't in 'nodes' are ignored.
+pub fn all_pairs<N:Clone + Eq + Hash>(graph:&impl Graph<N>, nodes:&[N]) -> HashMap<(N, N), u64> {
+    let index = nodes.iter().enumerate().map(|(i, node)| (node, i)).collect::<HashMap<&N, usize>>();
+    let mut costs = vec![vec![None; nodes.len()]; nodes.len()];
+    for (i, node) in nodes.iter().enumerate() {
+        costs[i][i] = Some(0);
+        for (neighbour, step) in graph.neighbours(node) {
+            if let Some(&j) = index.get(&neighbour) {
+                costs[i][j] = Some(costs[i][j].map_or(step, |cost:u64| cost.min(step)));
+            }
+        }
+    }
+
+    // Allow paths through each node in turn (the costs from it don't change while doing so).
+    for k in 0..nodes.len() {
+        let from_k = costs[k].clone();
+        for row in costs.iter_mut() {
+            let Some(to_k) = row[k] else {continue};
+            for (cost, from_k) in row.iter_mut().zip(&from_k) {
+                if let Some(from_k) = from_k {
+                    if cost.is_none_or(|cost| to_k + from_k < cost) {
+                        *cost = Some(to_k + from_k);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut pairs = HashMap::new();
+    for (i, src) in nodes.iter().enumerate() {
+        for (j, dst) in nodes.iter().enumerate() {
+            if let Some(cost) = costs[i][j] {
+                pairs.insert((src.clone(), dst.clone()), cost);
+            }
+        }
+    }
+    pairs
+}
@@ -0,0 +1,156 @@
+use std::any::Any;
+use std::io::{BufRead, Cursor};
+
+use crate::answer::Answer;
+use crate::cancel::Cancel;
+use crate::error::Result;
+use crate::input::InputSource;
+use crate::params::Overrides;
+
+/// The kind of value a puzzle part produces.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum AnswerType {
+    Integer,
+    Text,
+    Picture, // Multi-line ASCII art (e.g. the day 10 CRT).
+    Empty,   // No answer (e.g. the missing second part of day 25).
+}
+
+impl AnswerType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnswerType::Integer => "integer",
+            AnswerType::Text => "text",
+            AnswerType::Picture => "picture",
+            AnswerType::Empty => "empty",
+        }
+    }
+}
+
+/// Description of a solver, used for listing and selecting implementations.
+#[derive(Clone, Debug)]
+pub struct SolverInfo {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub title: &'static str,
+    pub answer_types: [AnswerType; 2],
+    /// Names of the puzzle parameters that can be overridden.
+    pub params: &'static [&'static str],
+}
+
+impl SolverInfo {
+    pub fn new(year:u16, day:u8, name:&'static str, title:&'static str, answer_types:[AnswerType; 2]) -> Self {
+        SolverInfo {year, day, name, title, answer_types, params: &[]}
+    }
+
+    pub fn with_params(self, params:&'static [&'static str]) -> Self {
+        SolverInfo {params, ..self}
+    }
+}
+
+/// A puzzle solver.  The input is parsed once, then shared between both parts.  Solvers mustn't
+/// keep any state of their own, so that several days can be run at once (see 'run_days').
+pub trait Solver: Send + Sync {
+    fn info(&self) -> &SolverInfo;
+    /// Parse the lines, along with the puzzle parameters (the defaults depend on whether it's
+    /// the sample input).
+    fn parse(&self, lines:&[String], sample:bool, overrides:&Overrides) -> Result<Box<dyn Any>>;
+    /// Solve part 1, stopping with an error if 'cancel' is cancelled first.
+    fn part1(&self, input:&dyn Any, cancel:&Cancel) -> Result<Answer>;
+    /// Solve part 2, stopping with an error if 'cancel' is cancelled first.
+    fn part2(&self, input:&dyn Any, cancel:&Cancel) -> Result<Answer>;
+
+    /// Read the input and parse it.  Solvers that parse a line at a time read it as they go (see
+    /// `StreamSolver`), the rest read all the lines first.
+    fn read(&self, input:InputSource, sample:bool, overrides:&Overrides) -> Result<Box<dyn Any>> {
+        let info = self.info();
+        self.parse(&input.read_lines(info.year, info.day, sample)?, sample, overrides)
+    }
+
+    /// Parse the lines and run the selected part.
+    fn solve(&self, lines:&[String], second_part:bool, sample:bool, overrides:&Overrides, cancel:&Cancel) -> Result<Answer> {
+        let input = self.parse(lines, sample, overrides)?;
+        if !second_part {
+            self.part1(input.as_ref(), cancel)
+        } else {
+            self.part2(input.as_ref(), cancel)
+        }
+    }
+}
+
+/// Adapts a set of plain functions, sharing a parsed input type, into a `Solver`.  Overrides are
+/// checked against the parameters in the info before 'parse' is called.  Only the parts that
+/// might run for a long time need to check the 'Cancel' token.
+pub struct DaySolver<I> {
+    pub info: SolverInfo,
+    pub parse: fn(&[String], bool, &Overrides) -> Result<I>,
+    pub part1: fn(&I, &Cancel) -> Result<Answer>,
+    pub part2: fn(&I, &Cancel) -> Result<Answer>,
+}
+
+impl<I: 'static> DaySolver<I> {
+    fn input<'a>(&self, input:&'a dyn Any) -> &'a I {
+        input.downcast_ref::<I>().expect("input was not parsed by this solver")
+    }
+}
+
+impl<I: 'static> Solver for DaySolver<I> {
+    fn info(&self) -> &SolverInfo {
+        &self.info
+    }
+
+    fn parse(&self, lines:&[String], sample:bool, overrides:&Overrides) -> Result<Box<dyn Any>> {
+        overrides.check(self.info.day, self.info.params)?;
+        Ok(Box::new((self.parse)(lines, sample, overrides)?))
+    }
+
+    fn part1(&self, input:&dyn Any, cancel:&Cancel) -> Result<Answer> {
+        (self.part1)(self.input(input), cancel)
+    }
+
+    fn part2(&self, input:&dyn Any, cancel:&Cancel) -> Result<Answer> {
+        (self.part2)(self.input(input), cancel)
+    }
+}
+
+/// Like `DaySolver`, but parsing the input a line at a time from a reader (with a name for it in
+/// errors), so that the input needn't fit in memory.
+pub struct StreamSolver<I> {
+    pub info: SolverInfo,
+    pub read: fn(Box<dyn BufRead>, &str, bool, &Overrides) -> Result<I>,
+    pub part1: fn(&I, &Cancel) -> Result<Answer>,
+    pub part2: fn(&I, &Cancel) -> Result<Answer>,
+}
+
+impl<I: 'static> StreamSolver<I> {
+    fn input<'a>(&self, input:&'a dyn Any) -> &'a I {
+        input.downcast_ref::<I>().expect("input was not parsed by this solver")
+    }
+}
+
+impl<I: 'static> Solver for StreamSolver<I> {
+    fn info(&self) -> &SolverInfo {
+        &self.info
+    }
+
+    fn parse(&self, lines:&[String], sample:bool, overrides:&Overrides) -> Result<Box<dyn Any>> {
+        // Every line ends with a newline, so that a blank last line is still read.
+        let text = lines.iter().map(|line| format!("{}\n", line)).collect::<String>();
+        self.read(InputSource::Reader(Box::new(Cursor::new(text))), sample, overrides)
+    }
+
+    fn part1(&self, input:&dyn Any, cancel:&Cancel) -> Result<Answer> {
+        (self.part1)(self.input(input), cancel)
+    }
+
+    fn part2(&self, input:&dyn Any, cancel:&Cancel) -> Result<Answer> {
+        (self.part2)(self.input(input), cancel)
+    }
+
+    fn read(&self, input:InputSource, sample:bool, overrides:&Overrides) -> Result<Box<dyn Any>> {
+        overrides.check(self.info.day, self.info.params)?;
+        let (reader, name) = input.open(self.info.year, self.info.day, sample)?;
+        Ok(Box::new((self.read)(reader, &name, sample, overrides)?))
+    }
+}